@@ -18,6 +18,11 @@ pub struct Args {
     #[arg(long)]
     pub esm_imports: bool,
 
+    /// Imports namespaces with `import type` as well, as required by `verbatimModuleSyntax`, by
+    /// setting TS_GEN_IMPORT_TYPE
+    #[arg(long)]
+    pub import_type: bool,
+
     /// Formats the generated TypeScript files
     #[arg(long)]
     pub format: bool,
@@ -27,9 +32,54 @@ pub struct Args {
     #[arg(long = "index")]
     pub generate_index_ts: bool,
 
+    /// Adds a union of every exported type with the given name to the index.ts file
+    #[arg(long, value_name = "NAME", requires = "generate_index_ts")]
+    pub union_type: Option<String>,
+
+    /// Writes a package.json into your --output-directory, so the bindings can be
+    /// published as an npm package. Requires --index
+    #[arg(
+        long,
+        requires_all = ["generate_index_ts", "package_name", "package_version"]
+    )]
+    pub emit_package_json: bool,
+
+    /// The name of the package in the package.json emitted with --emit-package-json
+    #[arg(long, value_name = "NAME", requires = "emit_package_json")]
+    pub package_name: Option<String>,
+
+    /// The version of the package in the package.json emitted with --emit-package-json
+    #[arg(long, value_name = "VERSION", requires = "emit_package_json")]
+    pub package_version: Option<String>,
+
+    /// Writes a .prettierignore and an .eslintignore into your --output-directory,
+    /// so formatters and linters skip the generated bindings. Other ignore files
+    /// may be given instead, e.g. `--emit-ignore=.gitignore,.prettierignore`
+    #[arg(
+        long,
+        value_name = "FILE",
+        num_args = 0..=1,
+        require_equals = true,
+        value_delimiter = ','
+    )]
+    pub emit_ignore: Option<Vec<String>>,
+
     /// Do not capture `cargo test`'s output, and pass --nocapture to the test binary
     #[arg(long = "nocapture")]
     pub no_capture: bool,
+
+    /// Prints every exported file in addition to the summary
+    #[arg(long, short, conflicts_with = "quiet")]
+    pub verbose: bool,
+
+    /// Suppresses the summary printed after exporting
+    #[arg(long, short)]
+    pub quiet: bool,
+
+    /// Prints which files would be created or updated, without modifying the
+    /// --output-directory
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 // Args is in scope for the entirety of the main function, so this will only
@@ -31,10 +31,16 @@ pub fn invoke(args: &Args) -> Result<()> {
         } else {
             Stdio::piped()
         })
-        .env(
-            "TS_GEN_EXPORT_DIR",
-            path::absolute(path::export_dir(&args))?,
-        );
+        .env("TS_GEN_EXPORT_DIR", path::absolute(path::export_dir(args))?);
+
+    // the index is written by ts-gen itself, after exporting the types of each test
+    if args.generate_index_ts {
+        cargo_invocation.env("TS_GEN_INDEX", "true");
+    }
+
+    if args.import_type {
+        cargo_invocation.env("TS_GEN_IMPORT_TYPE", "true");
+    }
 
     feature!(cargo_invocation, args, {
         no_warnings => "no-serde-warnings",
@@ -0,0 +1,177 @@
+use std::{
+    fmt::{self, Display},
+    fs,
+    io::Write,
+    path::{Component, Path, PathBuf},
+};
+
+use color_eyre::{owo_colors::OwoColorize, Result};
+
+use crate::{metadata::FILE_NAME, path::absolute};
+
+/// What exporting would do to a file within the export directory
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Change {
+    Create,
+    Update,
+    Unchanged,
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Unchanged => "unchanged",
+        })
+    }
+}
+
+/// The directory types are exported to with `--dry-run`, instead of the actual export directory.
+/// Within it, the absolute path of the export directory is mirrored, so files exported outside of
+/// the export directory, e.g. using `#[ts(export_to = "../")]`, are staged as well.
+/// The staging directory is removed once this is dropped.
+pub struct StagingDir {
+    root: PathBuf,
+    export_dir: PathBuf,
+}
+
+impl StagingDir {
+    pub fn new(export_dir: &Path) -> Result<Self> {
+        let root = std::env::temp_dir().join(format!("ts-gen-dry-run-{}", std::process::id()));
+        Self::with_root(root, export_dir)
+    }
+
+    fn with_root(root: PathBuf, export_dir: &Path) -> Result<Self> {
+        let mirrored = absolute(export_dir)?
+            .components()
+            .filter(|c| matches!(c, Component::Normal(_)))
+            .collect::<PathBuf>();
+
+        Ok(Self {
+            export_dir: root.join(mirrored),
+            root,
+        })
+    }
+
+    /// The directory within the staging directory which mirrors the actual export directory
+    pub fn export_dir(&self) -> &Path {
+        &self.export_dir
+    }
+}
+
+impl Drop for StagingDir {
+    fn drop(&mut self) {
+        _ = fs::remove_dir_all(&self.root);
+    }
+}
+
+/// Compares every file in `staging` with its counterpart on disk.
+/// The returned paths are relative to `export_dir`. Files outside of it are listed by their
+/// absolute path.
+pub fn plan(staging: &StagingDir, export_dir: &Path) -> Result<Vec<(PathBuf, Change)>> {
+    let export_dir = absolute(export_dir)?;
+    // the part of the export directory which is not mirrored, e.g. `/` or `C:\`
+    let fs_root = export_dir
+        .components()
+        .take_while(|c| !matches!(c, Component::Normal(_)))
+        .collect::<PathBuf>();
+
+    let mut files = vec![];
+    collect_files(&staging.root, Path::new(""), &mut files)?;
+
+    let mut plan = files
+        .into_iter()
+        .map(|file| {
+            let target = fs_root.join(&file);
+            let change = match fs::read(&target) {
+                Err(..) => Change::Create,
+                Ok(existing) if existing == fs::read(staging.root.join(&file))? => {
+                    Change::Unchanged
+                }
+                Ok(..) => Change::Update,
+            };
+
+            let path = match target.strip_prefix(&export_dir) {
+                Ok(relative) => relative.to_owned(),
+                Err(..) => target,
+            };
+            Ok((path, change))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    plan.sort();
+    Ok(plan)
+}
+
+fn collect_files(base: &Path, relative: &Path, out: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(base.join(relative))? {
+        let entry = entry?;
+        let path = relative.join(entry.file_name());
+
+        if entry.file_type()?.is_dir() {
+            collect_files(base, &path, out)?;
+        } else if entry.file_name() != FILE_NAME {
+            out.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints the planned file operations, one file per line.
+pub fn report(plan: &[(PathBuf, Change)], out: &mut impl Write) -> Result<()> {
+    for (file, change) in plan {
+        let label = format!("{:>9}", change.to_string());
+        let label = match change {
+            Change::Create => label.green().bold().to_string(),
+            Change::Update => label.yellow().bold().to_string(),
+            Change::Unchanged => label.dimmed().to_string(),
+        };
+
+        writeln!(out, "{} {}", label, file.to_string_lossy())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_files() {
+        let dir = std::env::temp_dir().join("ts-gen-dry-run-test");
+        _ = fs::remove_dir_all(&dir);
+        let export = dir.join("project/bindings");
+        let staging = StagingDir::with_root(dir.join("staging"), &export).unwrap();
+        let staged = staging.export_dir();
+
+        fs::create_dir_all(staged.join("models")).unwrap();
+        fs::create_dir_all(&export).unwrap();
+        fs::write(staged.join("User.ts"), "type User = {};").unwrap();
+        fs::write(staged.join("models/Role.ts"), "type Role = {};").unwrap();
+        fs::write(staged.join("Token.ts"), "type Token = string;").unwrap();
+        fs::write(staged.join(FILE_NAME), "").unwrap();
+        fs::write(export.join("User.ts"), "type User = {};").unwrap();
+        fs::write(export.join("Token.ts"), "type Token = number;").unwrap();
+        // exported with `#[ts(export_to = "../shared/")]`
+        fs::create_dir_all(staged.join("../shared")).unwrap();
+        fs::write(staged.join("../shared/Id.ts"), "type Id = string;").unwrap();
+
+        let plan = plan(&staging, &export).unwrap();
+        drop(staging);
+        assert!(!dir.join("staging").exists());
+        _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            plan,
+            [
+                (dir.join("project/shared/Id.ts"), Change::Create),
+                (PathBuf::from("Token.ts"), Change::Update),
+                (PathBuf::from("User.ts"), Change::Unchanged),
+                (PathBuf::from("models/Role.ts"), Change::Create),
+            ]
+        );
+        assert!(!export.join("../shared").exists());
+    }
+}
@@ -0,0 +1,57 @@
+use std::{fs, path::Path};
+
+use color_eyre::Result;
+
+/// The ignore files written by --emit-ignore, if no file names are given
+pub const DEFAULT_FILE_NAMES: &[&str] = &[".prettierignore", ".eslintignore"];
+
+/// Writes ignore files into `export_dir` which match every file in it, so linters and formatters
+/// skip the generated bindings
+pub fn write(export_dir: &Path, file_names: &[String]) -> Result<()> {
+    let defaults = DEFAULT_FILE_NAMES.iter().map(|name| name.to_string());
+    let file_names = match file_names.is_empty() {
+        true => defaults.collect(),
+        false => file_names.to_vec(),
+    };
+
+    fs::create_dir_all(export_dir)?;
+    for file_name in file_names {
+        fs::write(export_dir.join(file_name), "*\n")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_ignore_files() {
+        let dir = std::env::temp_dir().join("ts-gen-ignore-test");
+        _ = fs::remove_dir_all(&dir);
+
+        write(&dir.join("default"), &[]).unwrap();
+        write(&dir.join("custom"), &[".gitignore".to_owned()]).unwrap();
+
+        let read = |path: &str| fs::read_to_string(dir.join(path)).ok();
+        let written = [
+            read("default/.prettierignore"),
+            read("default/.eslintignore"),
+            read("default/.gitignore"),
+            read("custom/.gitignore"),
+            read("custom/.prettierignore"),
+        ];
+        _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(
+            written,
+            [
+                Some("*\n".to_owned()),
+                Some("*\n".to_owned()),
+                None,
+                Some("*\n".to_owned()),
+                None,
+            ]
+        );
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    io::{Stderr, Write},
+    path::Path,
+    time::Duration,
+};
+
+use color_eyre::owo_colors::OwoColorize;
+
+use crate::{args::Args, metadata::Metadata};
+
+/// How much the CLI reports about what it did
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    Normal,
+    Verbose,
+}
+
+impl Level {
+    pub fn from_args(args: &Args) -> Self {
+        match (args.quiet, args.verbose) {
+            (true, _) => Self::Quiet,
+            (false, true) => Self::Verbose,
+            (false, false) => Self::Normal,
+        }
+    }
+}
+
+/// Writes leveled log messages. Messages go to stderr by default, so they never end up in
+/// output piped from stdout.
+pub struct Logger<W: Write> {
+    level: Level,
+    out: W,
+}
+
+impl Logger<Stderr> {
+    pub fn stderr(level: Level) -> Self {
+        Self::new(level, std::io::stderr())
+    }
+}
+
+impl<W: Write> Logger<W> {
+    pub fn new(level: Level, out: W) -> Self {
+        Self { level, out }
+    }
+
+    /// Prints a summary of the exported types. With `--verbose`, every exported file is listed.
+    pub fn summary(&mut self, metadata: &Metadata, export_dir: &Path, elapsed: Duration) {
+        if self.level == Level::Quiet {
+            return;
+        }
+
+        // multiple types may be exported to the same file
+        let mut paths = metadata.export_paths().collect::<Vec<_>>();
+        let types = paths.len();
+        paths.sort();
+        paths.dedup();
+
+        _ = writeln!(
+            self.out,
+            "{} exported {} type(s) to {} file(s) in {}, took {:.2}s",
+            "Info:".green().bold(),
+            types,
+            paths.len(),
+            export_dir.to_string_lossy(),
+            elapsed.as_secs_f64()
+        );
+
+        if self.level == Level::Verbose {
+            for path in paths {
+                _ = writeln!(
+                    self.out,
+                    "  {} {}",
+                    "-".blue().bold(),
+                    path.to_string_lossy()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const METADATA: &str = concat!(
+        "User,example::User,0,false,./User.ts\n",
+        "Role,example::Role,0,false,./models/Role.ts\n",
+        "Permission,example::Permission,0,false,./models/Role.ts\n",
+    );
+
+    fn summary(level: Level) -> String {
+        let metadata = Metadata::try_from(METADATA).unwrap();
+        let mut out = vec![];
+
+        Logger::new(level, &mut out).summary(
+            &metadata,
+            Path::new("./bindings"),
+            Duration::from_millis(1500),
+        );
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn verbose_lists_files() {
+        let out = summary(Level::Verbose);
+        assert!(out.contains("exported 3 type(s) to 2 file(s) in ./bindings, took 1.50s"));
+        assert!(out.contains("./User.ts"));
+        assert_eq!(out.matches("./models/Role.ts").count(), 1);
+    }
+
+    #[test]
+    fn normal_omits_files() {
+        let out = summary(Level::Normal);
+        assert!(out.contains("exported 3 type(s) to 2 file(s)"));
+        assert!(!out.contains("./User.ts"));
+    }
+
+    #[test]
+    fn quiet_prints_nothing() {
+        assert_eq!(summary(Level::Quiet), "");
+    }
+}
@@ -1,67 +1,118 @@
 use clap::Parser;
 use color_eyre::{owo_colors::OwoColorize, Result};
-use std::{fs, io::Write};
+use std::{fs, io::Write, time::Instant};
 
 mod args;
 mod cargo;
+mod dry_run;
+mod ignore;
+mod logger;
 mod metadata;
+mod package_json;
 mod path;
 
 use args::Args;
+use logger::{Level, Logger};
 use metadata::{Metadata, FILE_NAME};
 use path::export_dir;
 
-const NOTE: &[u8; 111] = b"// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n";
+const INDEX_FILE_NAME: &str = "index.ts";
 
 fn main() -> Result<()> {
     color_eyre::install()?;
 
-    let args = Args::parse();
+    let mut args = Args::parse();
+    let mut logger = Logger::stderr(Level::from_args(&args));
+    let start = Instant::now();
+
+    // With --dry-run, types are exported to a staging directory, which is compared against the
+    // actual export directory afterwards
+    let target_dir = export_dir(&args);
+    let staging = match args.dry_run {
+        true => Some(dry_run::StagingDir::new(&target_dir)?),
+        false => None,
+    };
+    if let Some(staging) = &staging {
+        args.output_directory = Some(staging.export_dir().to_owned());
+    }
 
     let metadata_path = export_dir(&args).join(FILE_NAME);
     if metadata_path.exists() {
         fs::remove_file(&metadata_path)?;
     }
 
+    // ts-gen adds to an existing index, so remove the index of the previous run
+    let index_path = export_dir(&args).join(INDEX_FILE_NAME);
+    if args.generate_index_ts && index_path.exists() {
+        fs::remove_file(&index_path)?;
+    }
+
     cargo::invoke(&args)?;
 
     let metadata_content = fs::read_to_string(&metadata_path)?;
     let metadata = Metadata::try_from(&*metadata_content)?;
 
-    if args.generate_index_ts {
-        if metadata.has_naming_collisions() {
-            metadata.report_naming_collisions();
+    if let Some(staging) = &staging {
+        generate_index(&args, &metadata)?;
+        generate_package_json(&args)?;
+        generate_ignore_files(&args)?;
 
-            eprintln!(
-                "{} due to the naming collisions listed above, generating an index.ts file is not possible",
-                "Error:".red().bold()
-            );
+        let plan = dry_run::plan(staging, &target_dir)?;
+        return dry_run::report(&plan, &mut std::io::stdout());
+    }
 
-            return Ok(());
-        }
+    logger.summary(&metadata, &export_dir(&args), start.elapsed());
 
-        if !metadata.is_empty() {
-            let index_path = export_dir(&args).join("index.ts");
+    generate_index(&args, &metadata)?;
+    generate_package_json(&args)?;
+    generate_ignore_files(&args)
+}
 
-            if index_path.exists() {
-                fs::remove_file(&index_path)?;
-            }
+fn generate_package_json(args: &Args) -> Result<()> {
+    match (
+        args.emit_package_json,
+        &args.package_name,
+        &args.package_version,
+    ) {
+        (true, Some(name), Some(version)) => package_json::write(&export_dir(args), name, version),
+        _ => Ok(()),
+    }
+}
 
-            let mut index = fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(index_path)?;
+fn generate_ignore_files(args: &Args) -> Result<()> {
+    match &args.emit_ignore {
+        Some(file_names) => ignore::write(&export_dir(args), file_names),
+        None => Ok(()),
+    }
+}
+
+fn generate_index(args: &Args, metadata: &Metadata) -> Result<()> {
+    if !args.generate_index_ts {
+        return Ok(());
+    }
+
+    let index_path = export_dir(args).join(INDEX_FILE_NAME);
 
-            index.write_all(NOTE)?;
+    if metadata.has_naming_collisions() {
+        metadata.report_naming_collisions();
 
-            for file in metadata.export_paths() {
-                // Debug formatter is important because it saves quotation marks and path breadcrumbs (like this "../../A.ts")
-                index.write_fmt(format_args!(
-                    "\nexport * from {:?};",
-                    file.to_string_lossy().replace(".ts", "")
-                ))?;
-            }
+        eprintln!(
+            "{} due to the naming collisions listed above, generating an index.ts file is not possible",
+            "Error:".red().bold()
+        );
+
+        if index_path.exists() {
+            fs::remove_file(&index_path)?;
         }
+
+        return Ok(());
+    }
+
+    // the re-exports of all exported modules were already written while exporting
+    if let (false, Some(name)) = (metadata.is_empty(), &args.union_type) {
+        let union_type = metadata.union_type(name, &fs::read_to_string(&index_path)?)?;
+        let mut index = fs::OpenOptions::new().append(true).open(index_path)?;
+        write!(index, "\n{}", union_type.trim_start())?;
     }
 
     Ok(())
@@ -4,7 +4,7 @@ use std::{
 };
 
 use color_eyre::{
-    eyre::{Error, OptionExt},
+    eyre::{eyre, Error, OptionExt},
     owo_colors::OwoColorize,
     Result,
 };
@@ -14,6 +14,10 @@ pub const FILE_NAME: &str = "ts_gen.meta";
 #[derive(PartialEq, Eq, Hash)]
 struct Entry<'a> {
     rust_name: &'a str,
+    /// The number of generic parameters of the type
+    generics: usize,
+    /// Whether the type is declared in the global scope using `#[ts(global)]`
+    global: bool,
     export_path: &'a Path,
 }
 
@@ -21,11 +25,17 @@ impl<'a> TryFrom<&'a str> for Entry<'a> {
     type Error = Error;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
-        let (rust_name, export_path) =
-            value.split_once(',').ok_or_eyre("Invalid metadata entry")?;
+        let mut fields = value.splitn(4, ',');
+        let mut field = || fields.next().ok_or_eyre("Invalid metadata entry");
+        let rust_name = field()?;
+        let generics = field()?.parse()?;
+        let global = field()?.parse()?;
+        let export_path = field()?;
 
         Ok(Self {
             rust_name,
+            generics,
+            global,
             export_path: Path::new(export_path),
         })
     }
@@ -74,6 +84,68 @@ impl<'a> Metadata<'a> {
     pub fn export_paths(&self) -> impl Iterator<Item = &Path> {
         self.entries.values().flatten().map(|x| x.export_path)
     }
+
+    /// Imports every exported type and declares a union of all of them, to be appended to the
+    /// index.ts file.
+    /// The modules are imported using the same specifiers they are re-exported with in `index`,
+    /// which ts-gen wrote with the configured import extension and quote style.
+    /// Generic types are part of the union with `unknown` arguments, and types declared with
+    /// `#[ts(global)]` are not imported, since they are already in scope.
+    pub fn union_type(&self, name: &str, index: &str) -> Result<String> {
+        let modules = index
+            .lines()
+            .filter_map(|line| {
+                line.trim()
+                    .strip_prefix("export * from ")?
+                    .strip_suffix(';')
+            })
+            .map(|specifier| (module_path(specifier), specifier))
+            .collect::<HashMap<_, _>>();
+
+        let mut types = self
+            .entries
+            .iter()
+            .flat_map(|(ts_type, entries)| entries.iter().map(move |x| (*ts_type, x)))
+            .collect::<Vec<_>>();
+        types.sort_by_key(|(ts_type, entry)| (*ts_type, entry.export_path));
+
+        let mut out = String::from("\n");
+        for (ts_type, entry) in types.iter().filter(|(_, entry)| !entry.global) {
+            let export_path = entry.export_path.to_string_lossy();
+            let path = export_path.trim_start_matches("./").trim_end_matches(".ts");
+            let specifier = modules
+                .get(path)
+                .ok_or_else(|| eyre!("{} is not re-exported from the index", export_path))?;
+            out.push_str(&format!(
+                "\nimport type {{ {} }} from {};",
+                ts_type, specifier
+            ));
+        }
+
+        let union = match types.is_empty() {
+            true => "never".to_owned(),
+            false => types
+                .iter()
+                .map(|(ts_type, entry)| match entry.generics {
+                    0 => ts_type.to_string(),
+                    n => format!("{ts_type}<{}>", vec!["unknown"; n].join(", ")),
+                })
+                .collect::<Vec<_>>()
+                .join(" | "),
+        };
+        out.push_str(&format!("\n\nexport type {name} = {union};"));
+        Ok(out)
+    }
+}
+
+/// The path of the module imported by `specifier` within the export directory, without quotes,
+/// leading `./` and extension, e.g. `models/UserRole` for `"./models/UserRole.js"`
+fn module_path(specifier: &str) -> &str {
+    specifier
+        .get(1..specifier.len().saturating_sub(1))
+        .unwrap_or_default()
+        .trim_start_matches("./")
+        .trim_end_matches(".js")
 }
 
 fn name_collision_warning(ts_type: &str, metadata: &HashSet<Entry>) {
@@ -102,3 +174,66 @@ fn name_collision_warning(ts_type: &str, metadata: &HashSet<Entry>) {
 
     eprintln!();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn union_type() {
+        let metadata = Metadata::try_from(concat!(
+            "User,example::User,0,false,./User.ts\n",
+            "Role,example::Role,0,false,./models/UserRole.ts\n",
+        ))
+        .unwrap();
+        let index = concat!(
+            "export * from \"./User\";\n",
+            "export * from \"./models/UserRole\";\n",
+        );
+
+        assert_eq!(
+            metadata.union_type("AllTypes", index).unwrap(),
+            concat!(
+                "\n",
+                "\nimport type { Role } from \"./models/UserRole\";",
+                "\nimport type { User } from \"./User\";",
+                "\n",
+                "\nexport type AllTypes = Role | User;",
+            )
+        );
+    }
+
+    #[test]
+    fn union_type_reuses_specifiers() {
+        let metadata = Metadata::try_from("User,example::User,0,false,./User.ts\n").unwrap();
+        let index = "export * from './User.js';\n";
+
+        assert_eq!(
+            metadata.union_type("AllTypes", index).unwrap(),
+            "\n\nimport type { User } from './User.js';\n\nexport type AllTypes = User;"
+        );
+    }
+
+    #[test]
+    fn union_type_generic_and_global() {
+        let metadata = Metadata::try_from(concat!(
+            "Pair,example::Pair,2,false,./Pair.ts\n",
+            "Config,example::Config,0,true,./Config.ts\n",
+        ))
+        .unwrap();
+        let index = concat!(
+            "export * from \"./Config\";\n",
+            "export * from \"./Pair\";\n",
+        );
+
+        assert_eq!(
+            metadata.union_type("AllTypes", index).unwrap(),
+            concat!(
+                "\n",
+                "\nimport type { Pair } from \"./Pair\";",
+                "\n",
+                "\nexport type AllTypes = Config | Pair<unknown, unknown>;",
+            )
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use std::{fmt::Write as _, fs, path::Path};
+
+use color_eyre::Result;
+
+pub const FILE_NAME: &str = "package.json";
+
+/// Writes a minimal package.json into `export_dir`, which allows publishing the bindings as an
+/// npm package
+pub fn write(export_dir: &Path, name: &str, version: &str) -> Result<()> {
+    fs::write(export_dir.join(FILE_NAME), generate(name, version))?;
+    Ok(())
+}
+
+fn generate(name: &str, version: &str) -> String {
+    format!(
+        "{{\n  \"name\": {},\n  \"version\": {},\n  \"types\": \"index.ts\"\n}}\n",
+        json_string(name),
+        json_string(version)
+    )
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn package_json() {
+        assert_eq!(
+            generate("@me/types", "1.2.3"),
+            concat!(
+                "{\n",
+                "  \"name\": \"@me/types\",\n",
+                "  \"version\": \"1.2.3\",\n",
+                "  \"types\": \"index.ts\"\n",
+                "}\n",
+            )
+        );
+    }
+
+    #[test]
+    fn escapes_strings() {
+        assert_eq!(json_string("a\"b\\c\n"), r#""a\"b\\c\u000a""#);
+    }
+}
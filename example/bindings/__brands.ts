@@ -0,0 +1,4 @@
+// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.
+export type Integer = number & { readonly __brand: "Integer" };
+
+export type Float = number & { readonly __brand: "Float" };
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.
+import type { Char } from "../__brands";
+
+export type Keymap = { leader: Char, name: string, bindings: { [key: string]: string }, };
\ No newline at end of file
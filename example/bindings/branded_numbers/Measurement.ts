@@ -0,0 +1,4 @@
+// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.
+import type { Float, Integer } from "../__brands";
+
+export type Measurement = { count: Integer, ratio: Float, total: bigint, by_sensor: { [key: number]: Float }, };
\ No newline at end of file
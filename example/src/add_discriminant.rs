@@ -0,0 +1,71 @@
+#![allow(dead_code)]
+
+use serde::Serialize;
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::FLOAT;
+
+#[derive(Serialize, TS)]
+#[ts(export, export_to = "add_discriminant/")]
+struct Circle {
+    radius: f64,
+}
+
+/// A shape which can be drawn
+#[derive(Serialize, TS)]
+#[serde(untagged)]
+#[ts(export, export_to = "add_discriminant/", add_discriminant = "_tag")]
+enum Shape {
+    Circle(Circle),
+    Rect { width: f64, height: f64 },
+    Point,
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "add_discriminant/",
+    untagged,
+    add_discriminant = "kind"
+)]
+enum Value {
+    Number(f64),
+    Text(String),
+    #[ts(rename = "nothing")]
+    Missing,
+}
+
+#[test]
+fn add_discriminant() {
+    assert_eq!(
+        Shape::inline(),
+        format!(
+            "{{ \"_tag\": \"Circle\" }} & Circle \
+             | {{ \"_tag\": \"Rect\", width: {FLOAT}, height: {FLOAT}, }} \
+             | {{ \"_tag\": \"Point\" }}"
+        )
+    );
+    assert_eq!(
+        Value::inline(),
+        format!(
+            "{{ \"kind\": \"Number\" }} & {FLOAT} \
+             | {{ \"kind\": \"Text\" }} & string \
+             | {{ \"kind\": \"nothing\" }}"
+        )
+    );
+}
+
+#[test]
+fn synthetic_remarks() {
+    assert_eq!(
+        Shape::DOCS,
+        Some(
+            "/**\n \
+             * A shape which can be drawn\n \
+             *\n \
+             * @remarks `_tag` is synthetic: it is not part of the serialized data, and only exists to narrow the union\n \
+             */\n"
+        )
+    );
+}
@@ -0,0 +1,41 @@
+#![allow(dead_code)]
+
+use std::ops::Bound;
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "bound/")]
+struct Price {
+    cents: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "bound/")]
+struct PriceFilter {
+    min: Bound<Price>,
+    max: Bound<u32>,
+}
+
+#[test]
+fn bound() {
+    assert_eq!(
+        PriceFilter::inline(),
+        format!(
+            concat!(
+                r#"{{ min: {{ "Included": Price }} | {{ "Excluded": Price }} | "Unbounded", "#,
+                r#"max: {{ "Included": {integer} }} | {{ "Excluded": {integer} }} | "Unbounded", }}"#
+            ),
+            integer = INTEGER
+        )
+    );
+    // brand types are imported from the prelude
+    #[cfg(not(feature = "branded-numbers"))]
+    assert_eq!(
+        PriceFilter::imports().unwrap(),
+        [r#"import type { Price } from "./Price";"#]
+    );
+}
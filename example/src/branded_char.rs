@@ -0,0 +1,34 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "branded_char/")]
+struct Keymap {
+    leader: char,
+    name: String,
+    bindings: HashMap<char, String>,
+}
+
+#[test]
+fn branded_char() {
+    assert_eq!(
+        Keymap::inline(),
+        "{ leader: Char, name: string, bindings: { [key: string]: string }, }"
+    );
+    assert_eq!(
+        Keymap::imports().unwrap(),
+        [r#"import type { Char } from "../__brands";"#]
+    );
+
+    Keymap::export_all().unwrap();
+    let prelude = std::fs::read_to_string(char::default_output_path().unwrap()).unwrap();
+    assert!(prelude.contains(concat!(
+        "/**\n",
+        " * @remarks single character\n",
+        " */\n",
+        "export type Char = string & { readonly __brand: \"Char\" };",
+    )));
+}
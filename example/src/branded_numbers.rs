@@ -0,0 +1,40 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "branded_numbers/")]
+struct Measurement {
+    count: u32,
+    ratio: f64,
+    total: u64,
+    by_sensor: HashMap<u16, f32>,
+}
+
+#[test]
+fn branded_numbers() {
+    #[cfg(not(feature = "js-collections"))]
+    assert_eq!(
+        Measurement::inline(),
+        "{ count: Integer, ratio: Float, total: bigint, by_sensor: { [key: number]: Float }, }"
+    );
+    #[cfg(feature = "js-collections")]
+    assert_eq!(
+        Measurement::inline(),
+        "{ count: Integer, ratio: Float, total: bigint, by_sensor: Map<Integer, Float>, }"
+    );
+    assert_eq!(
+        Measurement::imports().unwrap(),
+        [r#"import type { Float, Integer } from "../__brands";"#]
+    );
+
+    Measurement::export_all().unwrap();
+    let prelude = std::fs::read_to_string(u32::default_output_path().unwrap()).unwrap();
+    assert!(prelude.ends_with(concat!(
+        "export type Integer = number & { readonly __brand: \"Integer\" };\n",
+        "\n",
+        "export type Float = number & { readonly __brand: \"Float\" };",
+    )));
+}
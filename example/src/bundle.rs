@@ -0,0 +1,61 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export_to = "bundle/models/")]
+struct Role {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "bundle/")]
+struct User {
+    role: Role,
+    status: Status,
+    friends: Vec<User>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "bundle/")]
+enum Status {
+    Active,
+    Banned { reason: String },
+}
+
+#[derive(TS)]
+#[ts(export_to = "bundle/models/")]
+struct Group {
+    admin: User,
+    members: Vec<User>,
+}
+
+#[test]
+fn bundle() {
+    let path = std::env::temp_dir()
+        .join("ts-gen-bundle")
+        .join("bindings.ts");
+    Group::export_bundle_to(&path).unwrap();
+
+    let bundle = std::fs::read_to_string(&path).unwrap();
+    assert!(!bundle.lines().any(|line| line.starts_with("import")));
+
+    // every type is declared exactly once
+    let position = |decl: String| {
+        assert_eq!(bundle.matches(&format!("export {}", decl)).count(), 1);
+        bundle.find(&decl).unwrap()
+    };
+    let (role, status) = (position(Role::decl()), position(Status::decl()));
+    let (user, group) = (position(User::decl()), position(Group::decl()));
+
+    // dependencies are declared before the types using them
+    assert!(role < user && status < user && user < group);
+}
+
+#[test]
+fn primitives_cannot_be_bundled() {
+    let path = std::env::temp_dir()
+        .join("ts-gen-bundle")
+        .join("boolean.ts");
+    assert!(bool::export_bundle_to(path).is_err());
+}
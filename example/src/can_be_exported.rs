@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "can_be_exported/")]
+struct Account {
+    balance: u64,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "can_be_exported/")]
+enum Currency {
+    Euro,
+    Dollar,
+}
+
+#[test]
+fn can_be_exported() {
+    use std::collections::HashMap;
+
+    assert!(Account::can_be_exported());
+    assert!(Currency::can_be_exported());
+
+    assert!(!u64::can_be_exported());
+    assert!(!String::can_be_exported());
+    assert!(!Vec::<Account>::can_be_exported());
+    assert!(!HashMap::<String, Account>::can_be_exported());
+}
+
+#[test]
+fn is_primitive() {
+    assert!(u64::is_primitive());
+    assert!(u32::is_primitive());
+    assert!(f64::is_primitive());
+    assert!(char::is_primitive());
+    assert!(String::is_primitive());
+    assert!(Box::<str>::is_primitive());
+
+    assert!(!Account::is_primitive());
+    assert!(!Currency::is_primitive());
+    assert!(!Vec::<String>::is_primitive());
+}
+
+#[test]
+fn branded_primitives() {
+    // brand types are exported into the prelude, so they can be exported while being primitive
+    let branded = cfg!(feature = "branded-numbers");
+    assert_eq!(u32::can_be_exported(), branded);
+    assert_eq!(f64::can_be_exported(), branded);
+
+    let branded = cfg!(feature = "branded-char");
+    assert_eq!(char::can_be_exported(), branded);
+}
@@ -0,0 +1,18 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "chrono_duration_number/")]
+struct Timeout {
+    after: chrono::Duration,
+    retry: Option<chrono::Duration>,
+}
+
+#[test]
+fn chrono_duration_number() {
+    assert_eq!(
+        Timeout::inline(),
+        "{ after: number, retry: number | null, }"
+    );
+}
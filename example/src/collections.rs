@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+
+use std::collections::{BinaryHeap, LinkedList, VecDeque};
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::{FLOAT, INTEGER};
+
+#[derive(TS)]
+#[ts(export, export_to = "collections/")]
+struct User {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "collections/")]
+struct WaitingList {
+    queue: VecDeque<User>,
+}
+
+#[test]
+fn vec_deque() {
+    assert_eq!(VecDeque::<User>::ident(), "Array");
+    assert_eq!(WaitingList::inline(), "{ queue: Array<User>, }");
+    assert_eq!(
+        WaitingList::imports().unwrap(),
+        [r#"import type { User } from "./User";"#]
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "collections/")]
+struct Scheduler {
+    priorities: BinaryHeap<i32>,
+    history: LinkedList<User>,
+}
+
+#[test]
+fn binary_heap_and_linked_list() {
+    assert_eq!(
+        Scheduler::inline(),
+        format!("{{ priorities: Array<{INTEGER}>, history: Array<User>, }}")
+    );
+    // brand types are imported from the prelude
+    #[cfg(not(feature = "branded-numbers"))]
+    assert_eq!(
+        Scheduler::imports().unwrap(),
+        [r#"import type { User } from "./User";"#]
+    );
+}
+
+#[derive(TS, Clone)]
+#[ts(export, export_to = "collections/")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "collections/")]
+struct Polyline<'a> {
+    points: std::borrow::Cow<'a, [Point]>,
+    weights: Option<std::borrow::Cow<'a, [f32]>>,
+}
+
+#[test]
+fn cow_slice() {
+    use std::borrow::Cow;
+
+    assert_eq!(Cow::<[Point]>::name(), "Array<Point>");
+    assert_eq!(
+        Polyline::inline(),
+        format!("{{ points: Array<Point>, weights: Array<{FLOAT}> | null, }}")
+    );
+    // brand types are imported from the prelude
+    #[cfg(not(feature = "branded-numbers"))]
+    assert_eq!(
+        Polyline::imports().unwrap(),
+        [r#"import type { Point } from "./Point";"#]
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "collections/")]
+struct Batch<'a, T: Clone> {
+    items: std::borrow::Cow<'a, [T]>,
+}
+
+#[test]
+fn generic_cow_slice() {
+    assert_eq!(
+        Batch::<Point>::decl(),
+        "type Batch<T> = { items: Array<T>, };"
+    );
+    assert_eq!(Batch::<Point>::inline(), "{ items: Array<Point>, }");
+}
@@ -0,0 +1,69 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::{FLOAT, INTEGER};
+
+#[derive(TS)]
+#[ts(export, export_to = "decl_named/")]
+struct Coordinates {
+    lat: f64,
+    lng: f64,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "decl_named/")]
+struct Page<T> {
+    items: Vec<T>,
+    total: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "decl_named/", interface)]
+struct Account {
+    id: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "decl_named/", enum)]
+enum Status {
+    Active,
+    Suspended,
+}
+
+#[test]
+fn struct_under_two_names() {
+    assert_eq!(
+        Coordinates::decl_named("Origin"),
+        format!("type Origin = {{ lat: {FLOAT}, lng: {FLOAT}, }};")
+    );
+    assert_eq!(
+        Coordinates::decl_named("Destination"),
+        format!("type Destination = {{ lat: {FLOAT}, lng: {FLOAT}, }};")
+    );
+    assert_eq!(
+        Coordinates::decl(),
+        format!("type Coordinates = {{ lat: {FLOAT}, lng: {FLOAT}, }};")
+    );
+}
+
+#[test]
+fn generic() {
+    assert_eq!(
+        Page::<String>::decl_named("Results"),
+        format!("type Results<T> = {{ items: Array<T>, total: {INTEGER}, }};")
+    );
+}
+
+#[test]
+fn interface_and_enum() {
+    assert_eq!(
+        Account::decl_named("User"),
+        format!("interface User {{ id: {INTEGER}, }}")
+    );
+    assert_eq!(
+        Status::decl_named("State"),
+        r#"enum State { Active = "Active", Suspended = "Suspended" }"#
+    );
+}
@@ -0,0 +1,75 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "deprecated/")]
+struct Account {
+    id: u32,
+    /// The login of the account
+    #[deprecated(since = "1.2.0", note = "use `email` instead")]
+    username: String,
+    #[deprecated]
+    legacy_id: Option<u32>,
+    email: String,
+}
+
+/// Replaced by [`Account`]
+#[derive(TS)]
+#[ts(export, export_to = "deprecated/")]
+#[deprecated = "use `Account` instead"]
+struct User {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "deprecated/", enum)]
+enum Plan {
+    Free,
+    #[deprecated(note = "no longer offered")]
+    Basic,
+    Pro,
+}
+
+#[test]
+fn deprecated_field() {
+    assert_eq!(
+        Account::decl(),
+        format!(
+            concat!(
+                "type Account = {{ id: {integer}, \n",
+                "/**\n",
+                " * The login of the account\n",
+                " *\n",
+                " * @deprecated use `email` instead\n",
+                " */\n",
+                "username: string, \n",
+                "/**\n",
+                " * @deprecated\n",
+                " */\n",
+                "legacy_id: {integer} | null, email: string, }};"
+            ),
+            integer = INTEGER
+        )
+    );
+}
+
+#[test]
+#[allow(deprecated)]
+fn deprecated_type() {
+    assert_eq!(
+        User::DOCS,
+        Some("/**\n * Replaced by [`Account`]\n *\n * @deprecated use `Account` instead\n */\n")
+    );
+}
+
+#[test]
+fn deprecated_enum_member() {
+    assert_eq!(
+        Plan::decl(),
+        r#"enum Plan { Free = "Free", /** @deprecated no longer offered */ Basic = "Basic", Pro = "Pro" }"#
+    );
+}
@@ -0,0 +1,85 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+/// A user of the application.
+#[derive(TS)]
+#[ts(export, export_to = "docs/")]
+struct User {
+    /// Unique identifier
+    id: u32,
+}
+
+/// Internal notes which should not end up in TypeScript.
+#[derive(TS)]
+#[ts(export, export_to = "docs/", no_docs)]
+struct Account {
+    /// Also internal
+    id: u32,
+}
+
+#[test]
+fn docs() {
+    assert_eq!(User::DOCS, Some("/**\n * A user of the application.\n */\n"));
+    assert_eq!(
+        User::decl(),
+        format!("type User = {{ \n/**\n * Unique identifier\n */\nid: {INTEGER}, }};")
+    );
+}
+
+#[test]
+fn no_docs() {
+    assert_eq!(Account::DOCS, None);
+    assert_eq!(
+        Account::decl(),
+        format!("type Account = {{ id: {INTEGER}, }};")
+    );
+    assert!(!Account::export_to_string().unwrap().contains("/**"));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "docs/")]
+struct Connection {
+    /// Timeout in seconds
+    #[ts(optional, default = "30")]
+    timeout: Option<u32>,
+    #[ts(default = "\"localhost\"")]
+    host: String,
+    /// Use `tls` instead
+    #[deprecated]
+    #[ts(default = "false")]
+    secure: bool,
+}
+
+#[test]
+fn default_tag() {
+    assert_eq!(
+        Connection::decl(),
+        format!(
+            concat!(
+                "type Connection = {{ \n",
+                "/**\n",
+                " * Timeout in seconds\n",
+                " *\n",
+                " * @default 30\n",
+                " */\n",
+                "timeout?: {integer}, \n",
+                "/**\n",
+                " * @default \"localhost\"\n",
+                " */\n",
+                "host: string, \n",
+                "/**\n",
+                " * Use `tls` instead\n",
+                " *\n",
+                " * @deprecated\n",
+                " * @default false\n",
+                " */\n",
+                "secure: boolean, }};",
+            ),
+            integer = INTEGER
+        )
+    );
+}
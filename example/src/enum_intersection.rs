@@ -0,0 +1,113 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "enum_intersection/")]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "enum_intersection/",
+    tag = "kind",
+    variants_as_intersection
+)]
+enum Event {
+    Click { at: Point, button: u8 },
+    Scroll { delta: i32 },
+    Close,
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "enum_intersection/",
+    tag = "kind",
+    variants_as_intersection
+)]
+enum Message<T> {
+    Data { payload: T },
+    Ping,
+}
+
+#[test]
+fn intersection() {
+    assert_eq!(
+        Event::decl(),
+        r#"type Event = { "kind": "Click" } & EventClickData | { "kind": "Scroll" } & EventScrollData | { "kind": "Close" };"#
+    );
+    assert_eq!(
+        Event::helper_decls(),
+        vec![
+            format!("type EventClickData = {{ at: Point, button: {INTEGER}, }};"),
+            format!("type EventScrollData = {{ delta: {INTEGER}, }};"),
+        ]
+    );
+    assert!(Event::dependencies()
+        .iter()
+        .any(|dep| dep.ts_name == "Point"));
+}
+
+#[test]
+fn generic_intersection() {
+    assert_eq!(
+        Message::<String>::decl(),
+        r#"type Message<T> = { "kind": "Data" } & MessageDataData<T> | { "kind": "Ping" };"#
+    );
+    assert_eq!(
+        Message::<String>::decl_concrete(),
+        r#"type Message = { "kind": "Data" } & MessageDataData<string> | { "kind": "Ping" };"#
+    );
+    assert_eq!(
+        Message::<String>::helper_decls(),
+        vec!["type MessageDataData<T> = { payload: T, };"]
+    );
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "enum_intersection/",
+    namespace = "Api",
+    tag = "kind",
+    variants_as_intersection
+)]
+enum Request<T> {
+    Get { id: T },
+}
+
+#[test]
+fn namespaced_intersection() {
+    assert_eq!(
+        Request::<u32>::decl(),
+        r#"type Request<T> = { "kind": "Get" } & RequestGetData<T>;"#
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "enum_intersection/")]
+struct Inbox {
+    #[ts(inline)]
+    latest: Message<String>,
+    #[ts(inline)]
+    request: Request<u32>,
+}
+
+// the data types of the variants are only declared next to the enum, so they are inlined as well
+#[test]
+fn inlined_intersection() {
+    assert_eq!(
+        Inbox::inline(),
+        format!(
+            r#"{{ latest: {{ "kind": "Data", payload: string, }} | {{ "kind": "Ping" }}, request: {{ "kind": "Get", id: {INTEGER}, }}, }}"#
+        )
+    );
+    assert!(!Inbox::export_to_string().unwrap().contains("Data<"));
+}
@@ -0,0 +1,79 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "event_map/")]
+struct ClickData {
+    x: i32,
+    y: i32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "event_map/")]
+struct HoverData {
+    target: String,
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "event_map/",
+    rename_all = "lowercase",
+    event_map = "EventMap"
+)]
+enum Event {
+    Click(ClickData),
+    Hover(HoverData),
+    #[ts(rename = "key-down")]
+    KeyDown {
+        key: String,
+    },
+    Close,
+    #[ts(skip)]
+    Internal,
+}
+
+#[test]
+fn event_map() {
+    assert_eq!(
+        Event::helper_decls(),
+        vec![concat!(
+            "type EventMap = { ",
+            "click: ClickData, ",
+            "hover: HoverData, ",
+            r#""key-down": { key: string, }, "#,
+            "close: null, ",
+            "};"
+        )]
+    );
+
+    let exported = Event::export_to_string().unwrap();
+    assert!(exported.contains(r#"import type { ClickData } from "./ClickData";"#));
+    assert!(exported.ends_with("\n\nexport type EventMap = { click: ClickData, hover: HoverData, \"key-down\": { key: string, }, close: null, };"));
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "event_map/",
+    tag = "type",
+    event_map = "MessageMap"
+)]
+enum Message<T> {
+    Data(T),
+    Error { code: u16 },
+}
+
+#[test]
+fn generic() {
+    assert_eq!(
+        Message::<String>::helper_decls(),
+        vec![format!(
+            "type MessageMap<T> = {{ Data: T, Error: {{ code: {INTEGER}, }}, }};"
+        )]
+    );
+}
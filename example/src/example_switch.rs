@@ -0,0 +1,100 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+/// A shape which can be drawn.
+#[derive(TS)]
+#[ts(export, export_to = "example_switch/", tag = "kind", example_switch)]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    #[ts(rename = "rect")]
+    Rectangle {
+        width: f64,
+        height: f64,
+    },
+    Point,
+    #[ts(skip)]
+    Hidden,
+}
+
+#[test]
+fn example_switch() {
+    assert_eq!(
+        Shape::DOCS,
+        Some(concat!(
+            "/**\n",
+            " * A shape which can be drawn.\n",
+            " *\n",
+            " * @example\n",
+            " * switch (value.kind) {\n",
+            " *   case \"Circle\":\n",
+            " *     break;\n",
+            " *   case \"rect\":\n",
+            " *     break;\n",
+            " *   case \"Point\":\n",
+            " *     break;\n",
+            " * }\n",
+            " */\n",
+        ))
+    );
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "example_switch/",
+    tag = "message-type",
+    content = "data",
+    example_switch
+)]
+enum Message {
+    Text(String),
+    Ping,
+}
+
+#[test]
+fn without_docs() {
+    assert_eq!(
+        Message::DOCS,
+        Some(concat!(
+            "/**\n",
+            " * @example\n",
+            " * switch (value[\"message-type\"]) {\n",
+            " *   case \"Text\":\n",
+            " *     break;\n",
+            " *   case \"Ping\":\n",
+            " *     break;\n",
+            " * }\n",
+            " */\n",
+        ))
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "example_switch/", tag = "*/type", example_switch)]
+enum Escaped {
+    #[ts(rename = "say \"hi\"")]
+    Quoted,
+    #[ts(rename = "a*/b")]
+    Comment,
+}
+
+#[test]
+fn escaped() {
+    assert_eq!(
+        Escaped::DOCS,
+        Some(concat!(
+            "/**\n",
+            " * @example\n",
+            " * switch (value[\"*\\/type\"]) {\n",
+            " *   case \"say \\\"hi\\\"\":\n",
+            " *     break;\n",
+            " *   case \"a*\\/b\":\n",
+            " *     break;\n",
+            " * }\n",
+            " */\n",
+        ))
+    );
+}
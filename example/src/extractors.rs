@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "extractors/", extractors)]
+enum Event {
+    Click { x: i32, y: i32 },
+    Key(String),
+    Close,
+}
+
+#[test]
+fn externally_tagged() {
+    assert_eq!(
+        Event::helper_decls(),
+        vec![
+            r#"type Event_Click = Extract<Event, { "Click": unknown }>;"#,
+            r#"type Event_Key = Extract<Event, { "Key": unknown }>;"#,
+            r#"type Event_Close = Extract<Event, "Close">;"#,
+        ]
+    );
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "extractors/",
+    tag = "type",
+    rename_all = "snake_case",
+    extractors
+)]
+enum Shape<T> {
+    Circle {
+        radius: T,
+    },
+    Square {
+        side: T,
+    },
+    #[ts(skip)]
+    Unknown,
+}
+
+#[test]
+fn internally_tagged_generic() {
+    assert_eq!(
+        Shape::<f32>::helper_decls(),
+        vec![
+            r#"type Shape_Circle<T> = Extract<Shape<T>, { "type": "circle" }>;"#,
+            r#"type Shape_Square<T> = Extract<Shape<T>, { "type": "square" }>;"#,
+        ]
+    );
+}
+
+#[test]
+fn exported() {
+    let exported = Event::export_to_string().unwrap();
+    assert!(exported.ends_with("\n\nexport type Event_Close = Extract<Event, \"Close\">;"));
+}
@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "field_names_union/", field_names_union)]
+#[ts(rename_all = "camelCase")]
+struct User {
+    user_id: u32,
+    first_name: String,
+    #[ts(rename = "surname")]
+    last_name: String,
+    #[ts(skip)]
+    password: String,
+}
+
+#[test]
+fn field_names_union() {
+    assert_eq!(
+        User::helper_decls(),
+        [r#"type UserFields = "userId" | "firstName" | "surname";"#]
+    );
+    assert!(User::export_to_string()
+        .unwrap()
+        .ends_with(r#"export type UserFields = "userId" | "firstName" | "surname";"#));
+}
@@ -0,0 +1,48 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "file_name/",
+    rename = "UserDto",
+    file_name = "User"
+)]
+struct User {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "file_name/", file_name = "errors")]
+enum ApiError {
+    NotFound,
+    Unauthorized,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "file_name/")]
+struct Response {
+    user: User,
+    error: Option<ApiError>,
+}
+
+#[test]
+fn file_name() {
+    use std::path::Path;
+
+    assert_eq!(User::ident(), "UserDto");
+    assert_eq!(User::output_path(), Some(Path::new("file_name/User.ts")));
+    assert_eq!(
+        ApiError::output_path(),
+        Some(Path::new("file_name/errors.ts"))
+    );
+
+    assert_eq!(
+        Response::imports().unwrap(),
+        [
+            r#"import type { UserDto } from "./User";"#,
+            r#"import type { ApiError } from "./errors";"#,
+        ]
+    );
+}
@@ -0,0 +1,21 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "format/")]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[test]
+fn export_to_string_formatted() {
+    let raw = Point::export_to_string().unwrap();
+    let formatted = Point::export_to_string_formatted().unwrap();
+
+    assert_ne!(raw, formatted);
+    assert!(raw.contains("x: number, y: number, }"));
+    assert!(!formatted.contains(", }"));
+    assert!(formatted.ends_with(";\n"));
+}
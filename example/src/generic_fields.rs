@@ -4,13 +4,16 @@ use std::borrow::Cow;
 
 use ts_gen::TS;
 
+#[cfg(test)]
+use crate::INTEGER;
+
 #[derive(TS)]
 #[ts(export, export_to = "generic_fields/")]
 struct Newtype(Vec<Cow<'static, i32>>);
 
 #[test]
 fn newtype() {
-    assert_eq!(Newtype::inline(), "Array<number>");
+    assert_eq!(Newtype::inline(), format!("Array<{INTEGER}>"));
 }
 
 #[derive(TS)]
@@ -19,7 +22,7 @@ struct NewtypeNested(Vec<Vec<i32>>);
 
 #[test]
 fn newtype_nested() {
-    assert_eq!(NewtypeNested::inline(), "Array<Array<number>>");
+    assert_eq!(NewtypeNested::inline(), format!("Array<Array<{INTEGER}>>"));
 }
 
 #[test]
@@ -75,6 +78,6 @@ struct TupleNested(
 fn tuple_nested() {
     assert_eq!(
         TupleNested::inline(),
-        "[Array<Array<number>>, [Array<Array<number>>, Array<Array<number>>], [Array<Array<number>>, Array<Array<number>>, Array<Array<number>>]]"
+        format!("[Array<Array<{INTEGER}>>, [Array<Array<{INTEGER}>>, Array<Array<{INTEGER}>>], [Array<Array<{INTEGER}>>, Array<Array<{INTEGER}>>, Array<Array<{INTEGER}>>]]")
     );
 }
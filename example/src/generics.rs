@@ -1,5 +1,4 @@
 #![allow(clippy::box_collection, clippy::enum_variant_names, dead_code)]
-#![allow(dead_code)]
 
 use serde::Serialize;
 use std::{
@@ -10,6 +9,9 @@ use std::{
 
 use ts_gen::TS;
 
+#[cfg(test)]
+use crate::INTEGER;
+
 #[derive(TS)]
 #[ts(export, export_to = "generics/")]
 struct Generic<T>
@@ -84,9 +86,10 @@ fn test() {
         "type GenericAutoBound2<T> = { value: T, values: Array<T>, };"
     );
 
+    #[cfg(not(feature = "js-set"))]
     assert_eq!(
         Container::decl(),
-        "type Container = { foo: Generic<number>, bar: Array<Generic<number>>, baz: { [key: string]: Generic<string> }, };"
+        format!("type Container = {{ foo: Generic<{INTEGER}>, bar: Array<Generic<{INTEGER}>>, baz: {{ [key: string]: Generic<string> }}, }};")
     );
 }
 
@@ -107,7 +110,9 @@ enum GenericEnum<A, B, C> {
 fn generic_enum() {
     assert_eq!(
         GenericEnum::<(), (), ()>::decl(),
-        r#"type GenericEnum<A, B, C> = { "A": A } | { "B": [B, B, B] } | { "C": Array<C> } | { "D": Array<Array<Array<A>>> } | { "E": { a: A, b: B, c: C, } } | { "X": Array<number> } | { "Y": number } | { "Z": Array<Array<number>> };"#
+        format!(
+            r#"type GenericEnum<A, B, C> = {{ "A": A }} | {{ "B": [B, B, B] }} | {{ "C": Array<C> }} | {{ "D": Array<Array<Array<A>>> }} | {{ "E": {{ a: A, b: B, c: C, }} }} | {{ "X": Array<{INTEGER}> }} | {{ "Y": {INTEGER} }} | {{ "Z": Array<Array<{INTEGER}>> }};"#
+        )
     )
 }
 
@@ -210,7 +215,7 @@ fn inline_with_bounds() {
     );
     assert_eq!(
         ContainerWithBounds::decl(),
-        "type ContainerWithBounds = { g: GenericWithBounds<string>, gi: { t: string, }, t: number, };"
+        format!("type ContainerWithBounds = {{ g: GenericWithBounds<string>, gi: {{ t: string, }}, t: {INTEGER}, }};")
     );
 }
 
@@ -240,7 +245,7 @@ fn inline_with_default() {
     );
     assert_eq!(
         ContainerWithDefault::decl(),
-        "type ContainerWithDefault = { g: GenericWithDefault<string>, gi: { t: string, }, t: number, };"
+        format!("type ContainerWithDefault = {{ g: GenericWithDefault<string>, gi: {{ t: string, }}, t: {INTEGER}, }};")
     );
 }
 
@@ -272,7 +277,7 @@ fn default() {
 
     assert_eq!(
         BDefault::<()>::decl(),
-        "type BDefault<U = ADefault<number> | null> = { u: U, };"
+        format!("type BDefault<U = ADefault<{INTEGER}> | null> = {{ u: U, }};")
     );
     assert!(BDefault::<()>::dependencies()
         .iter()
@@ -280,7 +285,7 @@ fn default() {
 
     assert_eq!(
         YDefault::decl(),
-        "type YDefault = { a1: ADefault<string>, a2: ADefault<number>, };"
+        format!("type YDefault = {{ a1: ADefault<string>, a2: ADefault<{INTEGER}>, }};")
     )
 }
 
@@ -313,7 +318,7 @@ struct DTraitBounds<T: ToString, const N: usize> {
 fn trait_bounds() {
     assert_eq!(
         ATraitBounds::<i32>::decl(),
-        "type ATraitBounds<T = number> = { t: T, };"
+        format!("type ATraitBounds<T = {INTEGER}> = {{ t: T, }};")
     );
 
     assert_eq!(
@@ -323,7 +328,9 @@ fn trait_bounds() {
 
     assert_eq!(
         CTraitBounds::<&'static str, i32>::decl(),
-        r#"type CTraitBounds<T, K = number> = { "A": { t: T, } } | { "B": T } | "C" | { "D": [T, K] };"#
+        format!(
+            r#"type CTraitBounds<T, K = {INTEGER}> = {{ "A": {{ t: T, }} }} | {{ "B": T }} | "C" | {{ "D": [T, K] }};"#
+        )
     );
 
     let ty = format!(
@@ -380,7 +387,7 @@ struct GenericParent<T> {
 fn deeply_nested() {
     assert_eq!(
         Parent::inline(),
-        "{ a: T1<T0<number>>, b: T1<P1<T0<P0<number>>>>, c: T1<P1<null>>, }"
+        format!("{{ a: T1<T0<{INTEGER}>>, b: T1<P1<T0<P0<{INTEGER}>>>>, c: T1<P1<null>>, }}")
     );
     assert_eq!(
         GenericParent::<()>::decl(),
@@ -421,9 +428,38 @@ fn inline_generic_enum() {
     // also the definition of `SomeType`.
     assert_eq!(
         ParentEnum::decl(),
-        "type ParentEnum = { \
-            e: MyEnum<number, number>, \
-            e1: { \"VariantA\": number } | { \"VariantB\": SomeType }, \
-        };"
+        format!(
+            "type ParentEnum = {{ \
+                e: MyEnum<{INTEGER}, {INTEGER}>, \
+                e1: {{ \"VariantA\": {INTEGER} }} | {{ \"VariantB\": SomeType }}, \
+            }};"
+        )
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "generics/")]
+struct FlattenedGeneric<T> {
+    id: u32,
+    #[ts(flatten)]
+    inner: T,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "generics/")]
+struct FlattenedInner {
+    a: i32,
+    b: String,
+}
+
+#[test]
+fn flattened_generic() {
+    assert_eq!(
+        FlattenedGeneric::<FlattenedInner>::decl(),
+        format!("type FlattenedGeneric<T> = {{ id: {INTEGER}, }} & T;")
+    );
+    assert_eq!(
+        FlattenedGeneric::<FlattenedInner>::inline(),
+        format!("{{ id: {INTEGER}, a: {INTEGER}, b: string, }}")
     );
 }
@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "global/")]
+struct Session {
+    token: String,
+}
+
+/// Injected into `window` by the server
+#[derive(TS)]
+#[ts(export, export_to = "global/", global)]
+struct AppConfig {
+    session: Session,
+    debug: bool,
+}
+
+#[test]
+fn global() {
+    assert_eq!((AppConfig::GLOBAL, Session::GLOBAL), (true, false));
+    assert_eq!(
+        AppConfig::decl(),
+        "type AppConfig = { session: Session, debug: boolean, };"
+    );
+
+    let exported = AppConfig::export_to_string().unwrap();
+    assert!(exported.contains(r#"import type { Session } from "./Session";"#));
+    assert!(exported.ends_with(concat!(
+        "declare global {\n",
+        "    /**\n",
+        "     * Injected into `window` by the server\n",
+        "     */\n",
+        "    type AppConfig = { session: Session, debug: boolean, };\n",
+        "}\n",
+        "\n",
+        "export {};",
+    )));
+}
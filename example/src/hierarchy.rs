@@ -0,0 +1,131 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::FLOAT;
+
+#[derive(TS)]
+#[ts(export, export_to = "hierarchy/", tag = "kind", hierarchy)]
+enum Shape {
+    Circle {
+        radius: f64,
+    },
+    #[ts(rename = "rect")]
+    Rectangle {
+        width: f64,
+        height: f64,
+    },
+    Point,
+    Empty {},
+    #[ts(skip)]
+    Hidden,
+}
+
+#[test]
+fn hierarchy() {
+    assert_eq!(
+        Shape::helper_decls(),
+        vec![
+            r#"interface ShapeBase { kind: "Circle" | "rect" | "Point" | "Empty", }"#.to_owned(),
+            format!(
+                r#"interface ShapeCircle extends ShapeBase {{ kind: "Circle", radius: {FLOAT}, }}"#
+            ),
+            format!(
+                r#"interface ShapeRectangle extends ShapeBase {{ kind: "rect", width: {FLOAT}, height: {FLOAT}, }}"#
+            ),
+            r#"interface ShapePoint extends ShapeBase { kind: "Point", }"#.to_owned(),
+            r#"interface ShapeEmpty extends ShapeBase { kind: "Empty", }"#.to_owned(),
+        ]
+    );
+
+    let exported = Shape::export_to_string().unwrap();
+    assert!(exported.contains(&format!(
+        "\n\nexport interface ShapeCircle extends ShapeBase {{ kind: \"Circle\", radius: {FLOAT}, }}"
+    )));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "hierarchy/")]
+struct Canvas {
+    #[ts(inline)]
+    background: Shape,
+}
+
+// the interfaces are only declared next to the enum, so the inlined enum must not refer to them
+#[test]
+fn inlined() {
+    assert_eq!(
+        Canvas::inline(),
+        format!(
+            r#"{{ background: {{ "kind": "Circle", radius: {FLOAT}, }} | {{ "kind": "rect", width: {FLOAT}, height: {FLOAT}, }} | {{ "kind": "Point" }} | {{ "kind": "Empty" }} & Record<string, never>, }}"#
+        )
+    );
+
+    let exported = Canvas::export_to_string().unwrap();
+    assert!(!exported.contains("ShapeBase"));
+    assert!(!exported.contains("ShapeCircle"));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "hierarchy/", tag = "type", hierarchy)]
+enum Node<T> {
+    Leaf { value: T },
+    Branch { children: Vec<Node<T>> },
+}
+
+#[test]
+fn generic() {
+    assert_eq!(
+        Node::<String>::helper_decls(),
+        vec![
+            r#"interface NodeBase { type: "Leaf" | "Branch", }"#,
+            r#"interface NodeLeaf<T> extends NodeBase { type: "Leaf", value: T, }"#,
+            r#"interface NodeBranch<T> extends NodeBase { type: "Branch", children: Array<Node<T>>, }"#,
+        ]
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "hierarchy/", tag = "kind", hierarchy)]
+enum Widget {
+    Label {
+        #[ts(type = "{ text: string }")]
+        content: String,
+    },
+}
+
+#[test]
+fn nested_object() {
+    assert_eq!(
+        Widget::helper_decls()[1],
+        r#"interface WidgetLabel extends WidgetBase { kind: "Label", content: { text: string }, }"#
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "hierarchy/", untagged)]
+enum Fill {
+    Solid { color: String },
+    Gradient { from: String, to: String },
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "hierarchy/", tag = "kind")]
+enum Layer {
+    Rectangle {
+        width: f64,
+        #[ts(flatten)]
+        fill: Fill,
+    },
+}
+
+#[test]
+fn flattened_variant() {
+    assert_eq!(
+        Layer::inline(),
+        format!(
+            r#"{{ "kind": "Rectangle", width: {FLOAT}, }} & ({{ color: string, }} | {{ from: string, to: string, }})"#
+        )
+    );
+}
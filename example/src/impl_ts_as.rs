@@ -0,0 +1,107 @@
+#![allow(dead_code)]
+
+use ts_gen::{impl_ts_as, TS};
+
+#[cfg(test)]
+use crate::{FLOAT, INTEGER};
+
+#[derive(TS)]
+#[ts(export, export_to = "impl_ts_as/")]
+struct Node {
+    label: String,
+}
+
+// serialized as a list of nodes
+struct Nodes(Vec<Node>);
+
+// serialized as a list of edges between nodes
+struct Edges {
+    edges: Vec<(usize, usize)>,
+}
+
+impl_ts_as! {
+    Nodes => Vec<Node>,
+    Edges => Vec<(usize, usize)>,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impl_ts_as/")]
+struct Network {
+    nodes: Nodes,
+    edges: Edges,
+}
+
+#[test]
+fn impl_ts_as() {
+    assert_eq!(Edges::name(), format!("Array<[{INTEGER}, {INTEGER}]>"));
+    assert_eq!(
+        Network::decl(),
+        format!("type Network = {{ nodes: Array<Node>, edges: Array<[{INTEGER}, {INTEGER}]>, }};")
+    );
+    assert!(Network::export_to_string()
+        .unwrap()
+        .contains("import type { Node } from \"./Node\";"));
+}
+
+// mirrors the shape of `uom::si::Quantity`, which is serialized as its value in base units
+mod si {
+    use std::marker::PhantomData;
+
+    pub trait Dimension {}
+
+    pub struct Length;
+    impl Dimension for Length {}
+
+    pub struct Quantity<D: Dimension + ?Sized, V> {
+        pub value: V,
+        dimension: PhantomData<D>,
+    }
+}
+
+impl_ts_as!(impl<D, V> self::si::Quantity<D, V> => V where D: si::Dimension + ?Sized, V: TS);
+
+#[derive(TS)]
+#[ts(export, export_to = "impl_ts_as/")]
+struct Route {
+    distance: si::Quantity<si::Length, f64>,
+    waypoints: Vec<si::Quantity<si::Length, f32>>,
+}
+
+#[test]
+fn generic_path() {
+    assert_eq!(
+        Route::decl(),
+        format!("type Route = {{ distance: {FLOAT}, waypoints: Array<{FLOAT}>, }};")
+    );
+}
+
+// custom GraphQL scalars, like those of async-graphql or juniper, are serialized as primitives
+struct Email(String);
+struct Cursor(u64);
+
+impl_ts_as! {
+    Email => String,
+    Cursor => u64,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impl_ts_as/")]
+struct Subscriber {
+    email: Email,
+    after: Option<Cursor>,
+}
+
+#[test]
+fn scalar() {
+    assert_eq!(Email::name(), "string");
+    assert_eq!(Email::inline(), "string");
+    assert!(Email::dependencies().is_empty());
+    assert!(!Email::can_be_exported());
+
+    assert_eq!(
+        Subscriber::decl(),
+        "type Subscriber = { email: string, after: bigint | null, };"
+    );
+    assert!(Subscriber::dependencies().is_empty());
+    assert!(!Subscriber::export_to_string().unwrap().contains("import"));
+}
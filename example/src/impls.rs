@@ -0,0 +1,193 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Subnets {
+    any: ipnet::IpNet,
+    v4: ipnet::Ipv4Net,
+    v6: Vec<ipnet::Ipv6Net>,
+}
+
+#[test]
+fn ipnet() {
+    assert_eq!(
+        Subnets::inline(),
+        "{ any: string, v4: string, v6: Array<string>, }"
+    );
+}
+
+#[derive(TS, enumset::EnumSetType)]
+#[ts(export, export_to = "impls/")]
+enum Permission {
+    Read,
+    Write,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Role {
+    permissions: enumset::EnumSet<Permission>,
+}
+
+#[test]
+fn enumset() {
+    assert_eq!(Role::inline(), "{ permissions: Array<Permission>, }");
+    assert!(Role::export_to_string()
+        .unwrap()
+        .contains(r#"import type { Permission } from "./Permission";"#));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Measurement {
+    value: fixed::types::I16F16,
+    total: fixed::types::U64F64,
+}
+
+#[test]
+fn fixed() {
+    assert_eq!(Measurement::inline(), "{ value: number, total: string, }");
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Document {
+    id: bson::oid::ObjectId,
+    created_at: bson::DateTime,
+    price: bson::Decimal128,
+}
+
+#[test]
+fn bson() {
+    assert_eq!(
+        Document::inline(),
+        "{ id: string, created_at: string, price: string, }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Template<'a> {
+    name: kstring::KString,
+    source: kstring::KStringCow<'a>,
+}
+
+#[test]
+fn kstring() {
+    assert_eq!(Template::inline(), "{ name: string, source: string, }");
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Tags {
+    ordered: indexmap::IndexSet<String>,
+    unique: std::collections::HashSet<u8>,
+    sorted: std::collections::BTreeSet<String>,
+}
+
+#[cfg(not(feature = "js-set"))]
+#[test]
+fn sets() {
+    use crate::INTEGER;
+
+    assert_eq!(
+        Tags::inline(),
+        format!("{{ ordered: Array<string>, unique: Array<{INTEGER}>, sorted: Array<string>, }}")
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Embedding {
+    weights: Vec<half::f16>,
+    scale: half::bf16,
+}
+
+#[test]
+fn half() {
+    assert_eq!(
+        Embedding::inline(),
+        "{ weights: Array<number>, scale: number, }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Schedule {
+    month: time::Month,
+    weekday: time::Weekday,
+}
+
+#[test]
+fn time() {
+    assert_eq!(time::Month::name(), "string");
+    assert_eq!(time::Weekday::name(), "string");
+    assert_eq!(Schedule::inline(), "{ month: string, weekday: string, }");
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Lease {
+    ttl: time::Duration,
+    renew_after: Option<time::Duration>,
+}
+
+#[cfg(not(feature = "time-duration-object"))]
+#[test]
+fn time_duration() {
+    assert_eq!(
+        Lease::inline(),
+        "{ ttl: string, renew_after: string | null, }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Timeout {
+    after: chrono::Duration,
+    retry: Option<chrono::Duration>,
+}
+
+#[cfg(not(feature = "chrono-duration-number"))]
+#[test]
+fn chrono_duration() {
+    assert_eq!(
+        Timeout::inline(),
+        "{ after: string, retry: string | null, }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Meeting {
+    starts_at: jiff::Zoned,
+    time_zone: jiff::tz::TimeZone,
+    ends_at: Option<jiff::Zoned>,
+}
+
+#[test]
+fn jiff() {
+    assert_eq!(jiff::Zoned::name(), "string");
+    assert_eq!(jiff::tz::TimeZone::name(), "string");
+    assert_eq!(
+        Meeting::inline(),
+        "{ starts_at: string, time_zone: string, ends_at: string | null, }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "impls/")]
+struct Route {
+    path: bytestring::ByteString,
+    aliases: Vec<bytestring::ByteString>,
+}
+
+#[test]
+fn bytestring() {
+    assert_eq!(
+        Route::inline(),
+        "{ path: string, aliases: Array<string>, }"
+    );
+}
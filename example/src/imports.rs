@@ -0,0 +1,75 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+use uuid::Uuid;
+
+#[derive(TS)]
+#[ts(export, export_to = "imports/")]
+struct Tokens {
+    tokens: Vec<Uuid>,
+}
+
+#[test]
+fn primitives_are_not_imported() {
+    assert_eq!(Tokens::inline(), "{ tokens: Array<string>, }");
+    assert!(Tokens::dependencies().is_empty());
+    assert!(!Tokens::export_to_string().unwrap().contains("import"));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "imports/user/")]
+struct Profile {
+    bio: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "imports/")]
+struct User {
+    tokens: Tokens,
+    profile: Profile,
+}
+
+#[test]
+fn imports() {
+    assert_eq!(
+        User::imports().unwrap(),
+        [
+            r#"import type { Tokens } from "./Tokens";"#,
+            r#"import type { Profile } from "./user/Profile";"#,
+        ]
+    );
+    assert!(Tokens::imports().unwrap().is_empty());
+}
+
+// both are declared in the same module, so they are not exported by tests
+#[derive(TS)]
+#[ts(export_to = "imports/shared.ts")]
+struct Price {
+    cents: u64,
+}
+
+#[derive(TS)]
+#[ts(export_to = "imports/shared.ts")]
+struct Currency {
+    code: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "imports/")]
+struct Order {
+    total: Price,
+    currency: Currency,
+    shipping: Option<Price>,
+    buyer: User,
+}
+
+#[test]
+fn merged_imports() {
+    assert_eq!(
+        Order::imports().unwrap(),
+        [
+            r#"import type { User } from "./User";"#,
+            r#"import type { Currency, Price } from "./shared";"#,
+        ]
+    );
+}
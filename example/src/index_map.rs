@@ -0,0 +1,45 @@
+#![allow(dead_code)]
+
+use indexmap::IndexMap;
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "index_map/")]
+struct Step {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "index_map/")]
+struct Pipeline {
+    steps: IndexMap<String, Step>,
+    weights: IndexMap<u8, f32>,
+}
+
+#[cfg(not(any(feature = "indexmap-ordered", feature = "js-collections")))]
+#[test]
+fn object() {
+    use crate::FLOAT;
+
+    assert_eq!(
+        Pipeline::inline(),
+        format!("{{ steps: {{ [key: string]: Step }}, weights: {{ [key: number]: {FLOAT} }}, }}")
+    );
+}
+
+#[cfg(feature = "indexmap-ordered")]
+#[test]
+fn ordered() {
+    use crate::{FLOAT, INTEGER};
+
+    assert_eq!(
+        Pipeline::inline(),
+        format!("{{ steps: Array<[string, Step]>, weights: Array<[{INTEGER}, {FLOAT}]>, }}")
+    );
+    // brand types are imported from the prelude
+    #[cfg(not(feature = "branded-numbers"))]
+    assert_eq!(
+        Pipeline::imports().unwrap(),
+        [r#"import type { Step } from "./Step";"#]
+    );
+}
@@ -0,0 +1,59 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "interface/", interface)]
+struct User {
+    id: u32,
+    name: String,
+    role: Role,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "interface/")]
+enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "interface/", interface, rename_all = "camelCase")]
+struct Page<T> {
+    items: Vec<T>,
+    next_cursor: Option<String>,
+}
+
+#[test]
+fn interface() {
+    assert_eq!(
+        User::decl(),
+        format!("interface User {{ id: {INTEGER}, name: string, role: Role, }}")
+    );
+    // brand types are imported from the prelude
+    #[cfg(not(feature = "branded-numbers"))]
+    assert_eq!(
+        User::export_to_string().unwrap(),
+        concat!(
+            "// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n",
+            "import type { Role } from \"./Role\";\n",
+            "\n",
+            "export interface User { id: number, name: string, role: Role, }",
+        )
+    );
+}
+
+#[test]
+fn generic() {
+    assert_eq!(
+        Page::<User>::decl(),
+        "interface Page<T> { items: Array<T>, nextCursor: string | null, }"
+    );
+    assert_eq!(
+        Page::<User>::decl_concrete(),
+        "interface Page { items: Array<User>, nextCursor: string | null, }"
+    );
+}
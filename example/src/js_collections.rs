@@ -0,0 +1,36 @@
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "js_collections/")]
+struct Member {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "js_collections/")]
+struct Team {
+    members: HashMap<u32, Member>,
+    roles: BTreeMap<String, Option<String>>,
+    tags: HashSet<String>,
+}
+
+#[test]
+fn js_collections() {
+    assert_eq!(
+        Team::inline(),
+        format!("{{ members: Map<{INTEGER}, Member>, roles: Map<string, string | null>, tags: Set<string>, }}")
+    );
+    // brand types are imported from the prelude
+    #[cfg(not(feature = "branded-numbers"))]
+    assert_eq!(
+        Team::imports().unwrap(),
+        [r#"import type { Member } from "./Member";"#]
+    );
+}
@@ -0,0 +1,37 @@
+#![allow(dead_code)]
+
+use std::collections::{BTreeSet, HashSet};
+
+use indexmap::IndexSet;
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "js_set/")]
+struct Label {
+    text: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "js_set/")]
+struct Tags {
+    ordered: IndexSet<String>,
+    unique: HashSet<u8>,
+    labels: BTreeSet<Label>,
+}
+
+#[test]
+fn js_set() {
+    assert_eq!(
+        Tags::inline(),
+        format!("{{ ordered: Set<string>, unique: Set<{INTEGER}>, labels: Set<Label>, }}")
+    );
+    // brand types are imported from the prelude
+    #[cfg(not(feature = "branded-numbers"))]
+    assert_eq!(
+        Tags::imports().unwrap(),
+        [r#"import type { Label } from "./Label";"#]
+    );
+}
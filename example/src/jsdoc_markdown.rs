@@ -0,0 +1,109 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+/// A point in 2D space, see [`Line`] and [Rust](https://www.rust-lang.org).
+///
+/// ```ignore
+/// # let origin = ();
+/// let point = Point { x: 0, y: 0 };
+/// ```
+#[derive(TS)]
+#[ts(export, export_to = "jsdoc_markdown/", jsdoc_markdown)]
+struct Point {
+    /// Horizontal position, `arr[i]` is not a link
+    x: i32,
+    /// Vertical position, relative to [`crate::Line::start`]
+    y: i32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "jsdoc_markdown/")]
+struct Line {
+    start: Point,
+    end: Point,
+}
+
+#[test]
+fn jsdoc_markdown() {
+    assert_eq!(
+        Point::DOCS,
+        Some(concat!(
+            "/**\n",
+            " * A point in 2D space, see {@link Line} and {@link https://www.rust-lang.org | Rust}.\n",
+            " *\n",
+            " * @example\n",
+            " * let point = Point { x: 0, y: 0 };\n",
+            " */\n",
+        ))
+    );
+    assert_eq!(
+        Point::decl(),
+        format!(
+            concat!(
+                "type Point = {{ \n",
+                "/**\n * Horizontal position, `arr[i]` is not a link\n */\nx: {integer}, \n",
+                "/**\n * Vertical position, relative to {{@link start}}\n */\ny: {integer}, }};",
+            ),
+            integer = INTEGER
+        )
+    );
+}
+
+/// Stores the value of cell i in a[i], which is neither [x] nor [`Point`][] nor [a line][Line].
+#[derive(TS)]
+#[ts(export, export_to = "jsdoc_markdown/", jsdoc_markdown)]
+struct Grid {
+    a: Vec<String>,
+}
+
+#[test]
+fn plain_brackets() {
+    assert_eq!(
+        Grid::DOCS,
+        Some(concat!(
+            "/**\n",
+            " * Stores the value of cell i in a[i], which is neither [x] nor {@link Point} nor {@link Line | a line}.\n",
+            " */\n",
+        ))
+    );
+}
+
+/// Draws a shape.
+///
+/// ```ignore
+/// draw(Shape::Circle);
+/// ```
+///
+/// Shapes are drawn in the order of their `z_index`.
+///
+/// ```text
+/// circle, square
+/// ```
+#[derive(TS)]
+#[ts(export, export_to = "jsdoc_markdown/", jsdoc_markdown)]
+struct Draw {
+    z_index: u32,
+}
+
+#[test]
+fn text_after_code_block() {
+    assert_eq!(
+        Draw::DOCS,
+        Some(concat!(
+            "/**\n",
+            " * Draws a shape.\n",
+            " *\n",
+            " * Shapes are drawn in the order of their `z_index`.\n",
+            " *\n",
+            " * @example\n",
+            " * draw(Shape::Circle);\n",
+            " * @example\n",
+            " * circle, square\n",
+            " */\n",
+        ))
+    );
+}
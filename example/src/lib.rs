@@ -1,12 +1,78 @@
 #![allow(dead_code, clippy::disallowed_names)]
 
+mod add_discriminant;
+#[cfg(feature = "branded-char")]
+mod branded_char;
+#[cfg(feature = "branded-numbers")]
+mod branded_numbers;
+mod bound;
+mod bundle;
+mod can_be_exported;
+#[cfg(feature = "chrono-duration-number")]
+mod chrono_duration_number;
+mod collections;
+mod decl_named;
+mod deprecated;
+mod docs;
+mod enum_intersection;
+mod event_map;
+mod example_switch;
+mod extractors;
+mod field_names_union;
+mod file_name;
+#[cfg(feature = "format")]
+mod format;
 mod generic_fields;
 mod generic_without_import;
 mod generics;
+mod hierarchy;
+mod global;
+mod impl_ts_as;
+mod impls;
+mod imports;
+mod index_map;
+mod interface;
+#[cfg(feature = "js-collections")]
+mod js_collections;
+#[cfg(feature = "js-set")]
+mod js_set;
+mod jsdoc_markdown;
+mod maps;
+#[cfg(feature = "export-module-path")]
+mod module_path;
+mod name_format;
+mod namespace;
+mod nested_imports;
+mod non_exhaustive;
+mod optional;
+mod output_path_in;
+mod parallel_export;
+mod ranges;
+mod readonly;
+mod result_shape;
+mod self_import;
+#[cfg(feature = "time-duration-object")]
+mod time_duration_object;
+mod transparent;
+mod ts_enum;
+mod tuple_labels;
+mod type_override;
+mod types;
+mod wrappers;
 
 use serde::Serialize;
 use ts_gen::TS;
 
+// the bindings of integers and floats, which are brand types with the `branded-numbers` feature
+#[cfg(not(feature = "branded-numbers"))]
+const INTEGER: &str = "number";
+#[cfg(feature = "branded-numbers")]
+const INTEGER: &str = "Integer";
+#[cfg(not(feature = "branded-numbers"))]
+const FLOAT: &str = "number";
+#[cfg(feature = "branded-numbers")]
+const FLOAT: &str = "Float";
+
 use std::{collections::BTreeSet, rc::Rc};
 
 use chrono::NaiveDateTime;
@@ -0,0 +1,100 @@
+#![allow(dead_code)]
+
+use std::collections::{BTreeMap, HashMap};
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "maps/")]
+struct Setting {
+    value: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "maps/")]
+struct Settings {
+    values: HashMap<String, Option<i32>>,
+    overrides: HashMap<String, Option<Option<Setting>>>,
+    #[ts(inline)]
+    inlined: BTreeMap<String, Option<Option<Setting>>>,
+}
+
+#[cfg(not(feature = "js-collections"))]
+#[test]
+fn optional_map_values() {
+    use crate::INTEGER;
+
+    assert_eq!(
+        Settings::inline(),
+        format!(
+            "{{ \
+                values: {{ [key: string]: {INTEGER} | null }}, \
+                overrides: {{ [key: string]: Setting | null }}, \
+                inlined: {{ [key: string]: {{ value: string, }} | null }}, \
+            }}"
+        )
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "maps/")]
+struct User {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "maps/")]
+struct Directory {
+    users: HashMap<String, User>,
+}
+
+#[test]
+fn map_ident() {
+    #[cfg(not(feature = "js-collections"))]
+    {
+        assert_eq!(HashMap::<String, User>::ident(), "Record");
+        assert_eq!(BTreeMap::<String, User>::ident(), "Record");
+    }
+
+    let dependencies = Directory::dependencies();
+    assert_eq!(dependencies.len(), 1);
+    assert_eq!(dependencies[0].ts_name, "User");
+}
+
+#[derive(TS, PartialEq, Eq, Hash)]
+#[ts(export, export_to = "maps/", rename_all = "kebab-case")]
+enum Theme {
+    Light,
+    DarkBlue,
+    HighContrast,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "maps/")]
+struct Palette {
+    accents: HashMap<Theme, String>,
+    #[ts(inline)]
+    contrast: BTreeMap<Theme, f32>,
+    by_name: HashMap<String, Theme>,
+}
+
+#[test]
+fn enum_keys() {
+    assert_eq!((Theme::LITERAL_UNION, Palette::LITERAL_UNION), (true, false));
+    assert_eq!(
+        Theme::inline(),
+        r#""light" | "dark-blue" | "high-contrast""#
+    );
+    #[cfg(not(feature = "js-collections"))]
+    assert_eq!(
+        Palette::inline(),
+        format!(
+            "{{ \
+                accents: {{ [key in Theme]?: string }}, \
+                contrast: {{ [key in \"light\" | \"dark-blue\" | \"high-contrast\"]?: {} }}, \
+                by_name: {{ [key: string]: Theme }}, \
+            }}",
+            crate::FLOAT
+        )
+    );
+}
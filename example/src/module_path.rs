@@ -0,0 +1,55 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+mod api {
+    use ts_gen::TS;
+
+    #[derive(TS)]
+    #[ts(export)]
+    pub struct User {
+        pub name: String,
+    }
+
+    pub mod admin {
+        use ts_gen::TS;
+
+        #[derive(TS)]
+        #[ts(export)]
+        pub struct Admin {
+            pub user: super::User,
+        }
+    }
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "module_path/")]
+struct Session {
+    admin: api::admin::Admin,
+}
+
+#[test]
+fn module_path() {
+    use std::path::Path;
+
+    assert_eq!(
+        api::User::output_path(),
+        Some(Path::new("module_path/api/User.ts"))
+    );
+    assert_eq!(
+        api::admin::Admin::output_path(),
+        Some(Path::new("module_path/api/admin/Admin.ts"))
+    );
+    // `export_to` takes precedence
+    assert_eq!(
+        Session::output_path(),
+        Some(Path::new("module_path/Session.ts"))
+    );
+
+    assert!(api::admin::Admin::export_to_string()
+        .unwrap()
+        .contains(r#"import type { User } from "../User";"#));
+    assert!(Session::export_to_string()
+        .unwrap()
+        .contains(r#"import type { Admin } from "./api/admin/Admin";"#));
+}
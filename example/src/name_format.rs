@@ -0,0 +1,50 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "name_format/", name_format = "Api{}")]
+struct User {
+    id: u32,
+    role: Role,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "name_format/", name_format = "Api{}")]
+enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(TS)]
+#[ts(
+    export,
+    export_to = "name_format/",
+    name_format = "Api{}",
+    rename = "Session"
+)]
+struct ApiSessionData {
+    token: String,
+}
+
+#[test]
+fn name_format() {
+    assert_eq!(User::name(), "ApiUser");
+    assert_eq!(Role::name(), "ApiRole");
+    assert_eq!(
+        User::decl(),
+        format!("type ApiUser = {{ id: {INTEGER}, role: ApiRole, }};")
+    );
+    assert_eq!(
+        User::output_path().unwrap(),
+        std::path::Path::new("name_format/ApiUser.ts")
+    );
+}
+
+#[test]
+fn rename_wins() {
+    assert_eq!(ApiSessionData::name(), "Session");
+}
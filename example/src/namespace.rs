@@ -0,0 +1,123 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "namespace/", namespace = "Api")]
+enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "namespace/", namespace = "Api.V1")]
+struct Team {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "namespace/")]
+struct Session {
+    token: String,
+}
+
+/// A user of the API
+#[derive(TS)]
+#[ts(export, export_to = "namespace/", namespace = "Api")]
+struct User {
+    name: String,
+    role: Role,
+    teams: Vec<Team>,
+    session: Option<Session>,
+}
+
+#[test]
+fn namespace() {
+    assert_eq!(User::NAMESPACE, Some("Api"));
+    assert_eq!(User::name(), "Api.User");
+    assert_eq!(User::ident(), "User");
+    assert_eq!(
+        ts_gen::Dependency::from_ty::<User>().unwrap().namespace(),
+        Some("Api")
+    );
+    assert_eq!(
+        User::decl(),
+        "type User = { name: string, role: Api.Role, teams: Array<Api.V1.Team>, session: Session | null, };"
+    );
+
+    assert_eq!(
+        User::export_to_string().unwrap(),
+        concat!(
+            "// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n",
+            "import { Api as __Api_Role } from \"./Role\";\n",
+            "import type { Session } from \"./Session\";\n",
+            "import { Api as __Api_V1_Team } from \"./Team\";\n",
+            "\n",
+            "export namespace Api { export import Role = __Api_Role.Role; }\n",
+            "\n",
+            "export namespace Api.V1 { export import Team = __Api_V1_Team.V1.Team; }\n",
+            "\n",
+            "export namespace Api {\n",
+            "    /**\n",
+            "     * A user of the API\n",
+            "     */\n",
+            "    export type User = { name: string, role: Api.Role, teams: Array<Api.V1.Team>, session: Session | null, };\n",
+            "}",
+        )
+    );
+}
+
+#[test]
+fn nested_namespace() {
+    assert_eq!(Team::name(), "Api.V1.Team");
+    assert!(Team::export_to_string().unwrap().ends_with(concat!(
+        "export namespace Api.V1 {\n",
+        "    export type Team = { name: string, };\n",
+        "}",
+    )));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "namespace/")]
+struct Member {
+    user: User,
+}
+
+#[test]
+fn import_namespace() {
+    assert_eq!(Member::inline(), "{ user: Api.User, }");
+    assert_eq!(
+        Member::export_to_string().unwrap(),
+        concat!(
+            "// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n",
+            "import type { Api } from \"./User\";\n",
+            "\n",
+            "export type Member = { user: Api.User, };",
+        )
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "namespace/")]
+struct Directory {
+    users: Vec<User>,
+    teams: Vec<Team>,
+}
+
+#[test]
+fn import_namespace_from_multiple_modules() {
+    assert_eq!(
+        Directory::export_to_string().unwrap(),
+        concat!(
+            "// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n",
+            "import { Api as __Api_V1_Team } from \"./Team\";\n",
+            "import { Api as __Api_User } from \"./User\";\n",
+            "\n",
+            "namespace Api { export import User = __Api_User.User; }\n",
+            "\n",
+            "namespace Api.V1 { export import Team = __Api_V1_Team.V1.Team; }\n",
+            "\n",
+            "export type Directory = { users: Array<Api.User>, teams: Array<Api.V1.Team>, };",
+        )
+    );
+}
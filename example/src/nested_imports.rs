@@ -0,0 +1,78 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "nested_imports/other/Bar.ts")]
+struct Bar {
+    id: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "nested_imports/models/")]
+struct Baz {
+    bar: Bar,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "nested_imports/models/deep/Foo.ts")]
+struct Foo {
+    bar: Bar,
+    baz: Baz,
+    qux: Qux,
+}
+
+// `\` is accepted as separator as well
+#[derive(TS)]
+#[ts(export, export_to = "nested_imports\\models\\deep\\deeper\\")]
+struct Qux {
+    baz: Baz,
+    bar: Bar,
+}
+
+#[test]
+fn nested_imports() {
+    assert_eq!(
+        Foo::imports().unwrap(),
+        [
+            r#"import type { Bar } from "../../other/Bar";"#,
+            r#"import type { Baz } from "../Baz";"#,
+            r#"import type { Qux } from "./deeper/Qux";"#,
+        ]
+    );
+    assert_eq!(
+        Baz::imports().unwrap(),
+        [r#"import type { Bar } from "../other/Bar";"#]
+    );
+}
+
+#[test]
+fn backslash_separators() {
+    use std::path::Path;
+
+    assert_eq!(
+        Qux::output_path(),
+        Some(Path::new("nested_imports/models/deep/deeper/Qux.ts"))
+    );
+    assert_eq!(
+        Qux::imports().unwrap(),
+        [
+            r#"import type { Bar } from "../../../other/Bar";"#,
+            r#"import type { Baz } from "../../Baz";"#,
+        ]
+    );
+}
+
+#[test]
+fn forward_slashes() {
+    use std::path::Path;
+
+    let dep = ts_gen::Dependency::from_ty::<Bar>().unwrap();
+    assert_eq!(dep.output_path, Path::new("nested_imports/other/Bar.ts"));
+
+    // import paths always use `/`, regardless of the platform's path separator
+    let imports = [Foo::imports(), Baz::imports(), Qux::imports()];
+    for import in imports.into_iter().flat_map(Result::unwrap) {
+        assert!(import.contains('/') && !import.contains('\\'), "{import}");
+    }
+}
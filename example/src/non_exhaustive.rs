@@ -0,0 +1,44 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "non_exhaustive/")]
+#[non_exhaustive]
+enum Status {
+    Active,
+    Suspended,
+    #[ts(skip)]
+    Deleted(u32),
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "non_exhaustive/", tag = "type", non_exhaustive)]
+enum Notification {
+    Message { text: String },
+    Reminder { at: u64 },
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "non_exhaustive/")]
+enum Exhaustive {
+    Yes,
+    No,
+}
+
+#[test]
+fn non_exhaustive() {
+    assert_eq!(
+        Status::inline(),
+        r#""Active" | "Suspended" | (string & {})"#
+    );
+    assert_eq!(
+        Notification::inline(),
+        concat!(
+            r#"{ "type": "Message", text: string, } | "#,
+            r#"{ "type": "Reminder", at: bigint, } | "#,
+            "{ [key: string]: unknown }"
+        )
+    );
+    assert_eq!(Exhaustive::inline(), r#""Yes" | "No""#);
+}
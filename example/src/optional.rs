@@ -0,0 +1,107 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::{FLOAT, INTEGER};
+
+#[derive(TS)]
+#[ts(export, export_to = "optional/")]
+struct Patch {
+    #[ts(optional)]
+    a: Option<i32>,
+    #[ts(optional = nullable)]
+    b: Option<i32>,
+    #[ts(optional = full)]
+    c: Option<i32>,
+    #[ts(optional = "full")]
+    d: Option<String>,
+}
+
+#[test]
+fn optional() {
+    assert_eq!(
+        Patch::inline(),
+        format!("{{ a?: {INTEGER}, b?: {INTEGER} | null, c?: {INTEGER} | null | undefined, d?: string | null | undefined, }}")
+    );
+}
+
+#[derive(TS, serde::Deserialize)]
+#[ts(export, export_to = "optional/")]
+struct Settings {
+    #[serde(default)]
+    #[ts(optional)]
+    theme: Option<String>,
+    #[serde(default)]
+    #[ts(optional = nullable)]
+    locale: Option<String>,
+    #[serde(default = "default_scale")]
+    #[ts(optional)]
+    scale: Option<f32>,
+}
+
+fn default_scale() -> Option<f32> {
+    Some(1.0)
+}
+
+#[test]
+fn serde_default() {
+    assert_eq!(
+        Settings::inline(),
+        format!("{{ theme?: string, locale?: string | null, scale?: {FLOAT}, }}")
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "optional/")]
+struct Update {
+    nickname: Option<Option<String>>,
+    #[ts(inline)]
+    age: Option<Option<Option<i32>>>,
+}
+
+#[test]
+fn nested_option() {
+    assert_eq!(Option::<Option<i32>>::inline(), format!("{INTEGER} | null"));
+    assert_eq!(Option::<Option<i32>>::name(), format!("{INTEGER} | null"));
+    assert_eq!(
+        Update::inline(),
+        format!("{{ nickname: string | null, age: {INTEGER} | null, }}")
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "optional/", optional)]
+struct Profile {
+    id: u32,
+    name: Option<String>,
+    bio: Option<String>,
+    age: Option<u8>,
+    #[ts(optional = nullable)]
+    avatar: Option<String>,
+}
+
+#[test]
+fn container() {
+    assert_eq!(
+        Profile::inline(),
+        format!("{{ id: {INTEGER}, name?: string, bio?: string, age?: {INTEGER}, avatar?: string | null, }}")
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "optional/", optional = nullable)]
+enum Event {
+    Renamed { from: Option<String>, to: String },
+    Deleted(Option<u32>),
+}
+
+#[test]
+fn container_enum() {
+    assert_eq!(
+        Event::inline(),
+        format!(
+            r#"{{ "Renamed": {{ from?: string | null, to: string, }} }} | {{ "Deleted": {INTEGER} | null }}"#
+        )
+    );
+}
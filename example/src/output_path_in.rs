@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "output_path_in/")]
+struct Invoice {
+    total: u32,
+}
+
+#[test]
+fn output_path_in() {
+    use std::path::Path;
+
+    let base = Path::new("/srv/frontend/src/bindings");
+
+    assert_eq!(
+        Invoice::output_path_in(base).unwrap(),
+        base.join("output_path_in/Invoice.ts")
+    );
+    assert_eq!(
+        Invoice::output_path_in(Path::new("")).as_deref(),
+        Invoice::output_path()
+    );
+    assert_eq!(String::output_path_in(base), None);
+}
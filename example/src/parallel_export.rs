@@ -0,0 +1,58 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export_to = "parallel_export/")]
+struct Author {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "parallel_export/")]
+struct Tag {
+    label: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "parallel_export/comments/")]
+struct Comment {
+    author: Author,
+    text: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "parallel_export/")]
+enum Status {
+    Draft,
+    Published { at: String },
+}
+
+#[derive(TS)]
+#[ts(export_to = "parallel_export/")]
+struct Post {
+    author: Author,
+    tags: Vec<Tag>,
+    comments: Vec<Comment>,
+    status: Status,
+}
+
+// with `parallel-export`, the files are written in parallel after all of them have been rendered,
+// which must not change their content
+#[test]
+fn parallel_export() {
+    let dir = std::env::temp_dir().join("ts-gen-parallel-export");
+    _ = std::fs::remove_dir_all(&dir);
+    Post::export_all_to(&dir).unwrap();
+
+    fn assert_exported<T: TS + 'static>(dir: &std::path::Path) {
+        let exported = std::fs::read_to_string(T::output_path_in(dir).unwrap()).unwrap();
+        assert_eq!(exported, T::export_to_string().unwrap());
+    }
+
+    assert_exported::<Author>(&dir);
+    assert_exported::<Tag>(&dir);
+    assert_exported::<Comment>(&dir);
+    assert_exported::<Status>(&dir);
+    assert_exported::<Post>(&dir);
+}
@@ -0,0 +1,38 @@
+#![allow(dead_code)]
+
+use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "ranges/")]
+struct Page {
+    index: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "ranges/")]
+struct Selection {
+    pages: Range<Page>,
+    from: RangeFrom<Page>,
+    to: RangeTo<u64>,
+    all: RangeFull,
+}
+
+#[test]
+fn ranges() {
+    assert_eq!(RangeFrom::<u32>::name(), format!("{{ start: {INTEGER}, }}"));
+    assert_eq!(RangeTo::<u32>::name(), format!("{{ end: {INTEGER}, }}"));
+    assert_eq!(RangeFull::name(), "null");
+    assert_eq!(
+        Selection::inline(),
+        "{ pages: { start: Page, end: Page, }, from: { start: Page, }, to: { end: bigint, }, all: null, }"
+    );
+    assert_eq!(
+        Selection::imports().unwrap(),
+        [r#"import type { Page } from "./Page";"#]
+    );
+}
@@ -0,0 +1,133 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "readonly/", readonly_deep)]
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "readonly/", readonly_deep)]
+struct Page<T> {
+    items: Vec<T>,
+    next: Option<String>,
+}
+
+#[test]
+fn readonly_deep() {
+    assert_eq!(
+        Config::decl(),
+        format!("type Config = Readonly<{{ name: string, retries: {INTEGER}, }}>;")
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "readonly/")]
+struct Service {
+    id: String,
+    #[ts(flatten)]
+    config: Config,
+}
+
+#[test]
+fn readonly_deep_flattened() {
+    assert_eq!(
+        Service::inline(),
+        format!("{{ id: string, }} & Readonly<{{ name: string, retries: {INTEGER}, }}>")
+    );
+}
+
+#[test]
+fn readonly_deep_generic() {
+    assert_eq!(
+        Page::<u32>::decl(),
+        "type Page<T> = Readonly<{ items: Array<T>, next: string | null, }>;"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "readonly/")]
+struct Snapshot<'a> {
+    #[ts(readonly)]
+    samples: &'a [u32],
+    #[ts(readonly)]
+    names: std::sync::Arc<[String]>,
+    #[ts(readonly, inline)]
+    pages: Box<[Page<u8>]>,
+    mutable: Vec<u32>,
+}
+
+#[test]
+fn readonly_slices() {
+    assert_eq!(
+        Snapshot::inline(),
+        format!(
+            concat!(
+                "{{ readonly samples: ReadonlyArray<{integer}>, ",
+                "readonly names: ReadonlyArray<string>, ",
+                "readonly pages: ReadonlyArray<Readonly<{{ items: Array<{integer}>, next: string | null, }}>>, ",
+                "mutable: Array<{integer}>, }}"
+            ),
+            integer = INTEGER
+        )
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "readonly/")]
+struct Audit {
+    at: String,
+    by: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "readonly/")]
+struct Order {
+    #[ts(readonly)]
+    id: u64,
+    #[ts(readonly, optional)]
+    coupon: Option<String>,
+    #[ts(readonly, flatten)]
+    audit: Audit,
+    note: String,
+}
+
+#[test]
+fn readonly_fields() {
+    assert_eq!(
+        Order::inline(),
+        "{ readonly id: bigint, readonly coupon?: string, note: string, } & Readonly<{ at: string, by: string, }>"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "readonly/", readonly, tag = "type")]
+struct Invoice {
+    number: u32,
+    #[ts(optional)]
+    paid_at: Option<String>,
+    lines: Box<[String]>,
+    #[ts(flatten)]
+    audit: Audit,
+}
+
+#[test]
+fn readonly_struct() {
+    assert_eq!(
+        Invoice::decl(),
+        format!(
+            concat!(
+                "type Invoice = {{ readonly type: \"Invoice\", readonly number: {integer}, ",
+                "readonly paid_at?: string, readonly lines: ReadonlyArray<string>, }} ",
+                "& Readonly<{{ at: string, by: string, }}>;"
+            ),
+            integer = INTEGER
+        )
+    );
+}
@@ -0,0 +1,70 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "result_shape/")]
+struct User {
+    id: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "result_shape/")]
+struct ApiError {
+    message: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "result_shape/", result_shape)]
+enum ApiResponse {
+    Ok(User),
+    Err(ApiError),
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "result_shape/", result_shape)]
+enum Response<T> {
+    Success(Vec<T>),
+    Failure(#[ts(type = "string")] ApiError),
+}
+
+#[test]
+fn result_shape() {
+    assert_eq!(
+        ApiResponse::decl(),
+        "type ApiResponse = { success: true, data: User } | { success: false, error: ApiError };"
+    );
+    assert!(ApiResponse::dependencies()
+        .iter()
+        .any(|dep| dep.ts_name == "User"));
+    assert!(ApiResponse::dependencies()
+        .iter()
+        .any(|dep| dep.ts_name == "ApiError"));
+}
+
+#[test]
+fn generic_result_shape() {
+    assert_eq!(
+        Response::<u32>::decl(),
+        "type Response<T> = { success: true, data: Array<T> } | { success: false, error: string };"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "result_shape/", result_shape)]
+enum Lookup {
+    #[ts(skip)]
+    Pending,
+    Found(User),
+    #[ts(skip)]
+    Cached(std::sync::Arc<User>),
+    Missing(ApiError),
+}
+
+#[test]
+fn skipped_variants() {
+    assert_eq!(
+        Lookup::inline(),
+        "{ success: true, data: User } | { success: false, error: ApiError }"
+    );
+}
@@ -0,0 +1,42 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "self_import/")]
+struct Node {
+    children: Vec<Node>,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "self_import/")]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+    labels: Option<Box<Tree<String>>>,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "self_import/")]
+struct Forest {
+    trees: Vec<Tree<Node>>,
+}
+
+#[test]
+fn recursive() {
+    assert!(Node::imports().unwrap().is_empty());
+    assert!(!Node::export_to_string().unwrap().contains("import"));
+}
+
+#[test]
+fn recursive_generic() {
+    // `Tree<String>` is a different type than `Tree<T>`, but declared in the same file
+    assert!(Tree::<bool>::imports().unwrap().is_empty());
+    assert_eq!(
+        Forest::imports().unwrap(),
+        [
+            r#"import type { Node } from "./Node";"#,
+            r#"import type { Tree } from "./Tree";"#,
+        ]
+    );
+}
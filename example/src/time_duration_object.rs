@@ -0,0 +1,26 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "time_duration_object/")]
+struct Lease {
+    ttl: time::Duration,
+    renew_after: Option<time::Duration>,
+}
+
+#[test]
+fn time_duration_object() {
+    assert_eq!(
+        Lease::inline(),
+        format!("{{ ttl: {{ seconds: {INTEGER}, nanoseconds: {INTEGER}, }}, renew_after: {{ seconds: {INTEGER}, nanoseconds: {INTEGER}, }} | null, }}")
+    );
+    #[cfg(feature = "branded-numbers")]
+    assert_eq!(
+        Lease::imports().unwrap(),
+        [r#"import type { Integer } from "../__brands";"#]
+    );
+}
@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/")]
+struct Inner {
+    a: i32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/", transparent)]
+struct Wrapper(Inner);
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/", transparent)]
+struct Named {
+    inner: Inner,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/", transparent)]
+struct Ids {
+    ids: Vec<u32>,
+}
+
+#[test]
+fn transparent() {
+    assert_eq!(Wrapper::decl(), "type Wrapper = Inner;");
+    assert_eq!(Named::decl(), "type Named = Inner;");
+    assert_eq!(Named::inline(), "Inner");
+    assert_eq!(Ids::decl(), format!("type Ids = Array<{INTEGER}>;"));
+    assert!(Named::export_to_string()
+        .unwrap()
+        .contains(r#"import type { Inner } from "./Inner";"#));
+}
@@ -0,0 +1,97 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "ts_enum/", enum)]
+enum Direction {
+    Up,
+    Down,
+    #[ts(skip)]
+    Sideways,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "ts_enum/", enum, rename_all = "snake_case")]
+enum Status {
+    InProgress,
+    Done,
+    #[ts(rename = "won't-do")]
+    WontDo,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "ts_enum/")]
+struct Task {
+    status: Status,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "ts_enum/", enum = "numeric")]
+enum Level {
+    Low = 1,
+    Medium = 5,
+    High,
+    Critical = -1,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "ts_enum/", enum = "numeric")]
+enum Ordinal {
+    First,
+    Second,
+    #[ts(skip)]
+    Third,
+    Fourth,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "ts_enum/", enum = "numeric")]
+#[repr(i128)]
+enum Bound {
+    Zero,
+    Max = 170141183460469231731687303715884105727,
+}
+
+#[test]
+fn default_members() {
+    assert_eq!(
+        Direction::decl(),
+        r#"enum Direction { Up = "Up", Down = "Down" }"#
+    );
+    assert_eq!(Direction::inline(), r#""Up" | "Down""#);
+}
+
+#[test]
+fn renamed_members() {
+    assert_eq!(
+        Status::decl(),
+        r#"enum Status { InProgress = "in_progress", Done = "done", WontDo = "won't-do" }"#
+    );
+    assert_eq!(
+        Status::export_to_string().unwrap(),
+        concat!(
+            "// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n",
+            "\n",
+            r#"export enum Status { InProgress = "in_progress", Done = "done", WontDo = "won't-do" }"#,
+        )
+    );
+    assert_eq!(Task::decl(), "type Task = { status: Status, };");
+}
+
+#[test]
+fn numeric_members() {
+    assert_eq!(
+        Level::decl(),
+        "enum Level { Low = 1, Medium = 5, High = 6, Critical = -1 }"
+    );
+    assert_eq!(Level::inline(), "1 | 5 | 6 | -1");
+    assert_eq!(
+        Ordinal::decl(),
+        "enum Ordinal { First = 0, Second = 1, Fourth = 3 }"
+    );
+    assert_eq!(
+        Bound::decl(),
+        "enum Bound { Zero = 0, Max = 170141183460469231731687303715884105727 }"
+    );
+}
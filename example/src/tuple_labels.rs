@@ -0,0 +1,63 @@
+#![allow(dead_code)]
+
+use ts_gen::TS;
+
+#[cfg(test)]
+use crate::{FLOAT, INTEGER};
+
+#[derive(TS)]
+#[ts(export, export_to = "tuple_labels/", tuple_labels = "x, y, z")]
+struct Point(f32, f32, f32);
+
+#[derive(TS)]
+#[ts(export, export_to = "tuple_labels/", tuple_labels = "id,name")]
+struct Entry(u32, #[ts(skip)] bool, String);
+
+#[derive(TS)]
+#[ts(export, export_to = "tuple_labels/", tuple_labels = "value")]
+struct Wrapper(Point);
+
+#[derive(TS)]
+#[ts(export, export_to = "tuple_labels/")]
+struct Vertex(#[ts(tuple_labels = "u, v")] [f64; 2]);
+
+#[derive(TS)]
+#[ts(export, export_to = "tuple_labels/")]
+struct Mesh {
+    #[ts(tuple_labels = "r, g, b")]
+    color: [u8; 3],
+    #[ts(tuple_labels = "origin, size", inline)]
+    bounds: (Point, [u32; 2]),
+}
+
+#[test]
+fn tuple_struct() {
+    assert_eq!(
+        Point::decl(),
+        format!("type Point = [x: {FLOAT}, y: {FLOAT}, z: {FLOAT}];")
+    );
+    assert_eq!(
+        Entry::decl(),
+        format!("type Entry = [id: {INTEGER}, name: string];")
+    );
+}
+
+#[test]
+fn single_field() {
+    assert_eq!(Wrapper::decl(), "type Wrapper = [value: Point];");
+    assert_eq!(
+        Vertex::decl(),
+        format!("type Vertex = [u: {FLOAT}, v: {FLOAT}];")
+    );
+}
+
+#[test]
+fn fields() {
+    assert_eq!(
+        Mesh::inline(),
+        format!(
+            "{{ color: [r: {INTEGER}, g: {INTEGER}, b: {INTEGER}], \
+               bounds: [origin: [x: {FLOAT}, y: {FLOAT}, z: {FLOAT}], size: [{INTEGER}, {INTEGER}]], }}"
+        )
+    );
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "type_override/")]
+struct User {
+    id: u32,
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "type_override/")]
+struct Paginated<T> {
+    items: Vec<T>,
+    next: Option<String>,
+    #[ts(type = "{ [K in keyof {T}]?: \"asc\" | \"desc\" }")]
+    sort: HashMap<String, String>,
+    #[ts(type = "{T}[keyof {T}]")]
+    any_value: String,
+    #[ts(type = "Record<string, {}>")]
+    meta: HashMap<String, String>,
+}
+
+#[test]
+fn generic_interpolation() {
+    assert_eq!(
+        Paginated::<User>::decl(),
+        concat!(
+            "type Paginated<T> = { items: Array<T>, next: string | null, ",
+            r#"sort: { [K in keyof T]?: "asc" | "desc" }, "#,
+            "any_value: T[keyof T], ",
+            "meta: Record<string, {}>, }",
+            ";"
+        )
+    );
+    assert_eq!(
+        Paginated::<User>::inline(),
+        concat!(
+            "{ items: Array<User>, next: string | null, ",
+            r#"sort: { [K in keyof User]?: "asc" | "desc" }, "#,
+            "any_value: User[keyof User], ",
+            "meta: Record<string, {}>, }"
+        )
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "type_override/")]
+struct Keys<T>(#[ts(type = "Array<keyof {T}>")] Vec<String>, T);
+
+#[derive(TS)]
+#[ts(export, export_to = "type_override/", tag = "kind")]
+enum Patch<T> {
+    Set {
+        #[ts(type = "Partial<{T}>")]
+        value: String,
+    },
+    Replace(T),
+}
+
+#[test]
+fn tuples_and_enums() {
+    assert_eq!(
+        Keys::<User>::decl(),
+        "type Keys<T> = [Array<keyof T>, T];"
+    );
+    assert_eq!(
+        Patch::<User>::inline(),
+        r#"{ "kind": "Set", value: Partial<User>, } | { "kind": "Replace" } & User"#
+    );
+}
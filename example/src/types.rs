@@ -0,0 +1,162 @@
+#![allow(dead_code)]
+
+use std::time::Duration;
+
+use ts_gen::{
+    types::{DurationMillis, DurationSecondsFloat},
+    TS,
+};
+
+#[cfg(test)]
+use crate::INTEGER;
+
+#[derive(TS)]
+#[ts(export, export_to = "types/")]
+struct Timeouts {
+    #[ts(as = "DurationMillis")]
+    connect: Duration,
+    #[ts(as = "DurationSecondsFloat")]
+    idle: Duration,
+    #[ts(as = "Option<DurationMillis>")]
+    request: Option<Duration>,
+}
+
+#[test]
+fn durations() {
+    assert_eq!(DurationMillis::name(), "number");
+    assert_eq!(DurationSecondsFloat::name(), "number");
+    assert_eq!(
+        Timeouts::inline(),
+        "{ connect: number, idle: number, request: number | null, }"
+    );
+    assert!(!Timeouts::export_to_string().unwrap().contains("import"));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "types/")]
+struct RetryPolicy {
+    backoff: Duration,
+    max_wait: Option<Duration>,
+}
+
+#[test]
+fn std_duration() {
+    assert_eq!(
+        Duration::name(),
+        format!("{{ secs: {INTEGER}, nanos: {INTEGER}, }}")
+    );
+    assert_eq!(
+        RetryPolicy::inline(),
+        format!("{{ backoff: {{ secs: {INTEGER}, nanos: {INTEGER}, }}, max_wait: {{ secs: {INTEGER}, nanos: {INTEGER}, }} | null, }}")
+    );
+    #[cfg(not(feature = "branded-numbers"))]
+    assert!(RetryPolicy::dependencies().is_empty());
+    #[cfg(feature = "branded-numbers")]
+    assert_eq!(
+        RetryPolicy::imports().unwrap(),
+        [r#"import type { Integer } from "../__brands";"#]
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "types/")]
+struct Counters {
+    running: std::sync::atomic::AtomicBool,
+    total: std::sync::atomic::AtomicU64,
+    offset: std::sync::atomic::AtomicI64,
+}
+
+#[test]
+fn atomics() {
+    use std::sync::atomic::{AtomicBool, AtomicU64};
+
+    assert_eq!(AtomicBool::inline(), "boolean");
+    assert_eq!(AtomicU64::inline(), "bigint");
+    assert_eq!(
+        Counters::inline(),
+        "{ running: boolean, total: bigint, offset: bigint, }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "types/")]
+struct Process<'a> {
+    program: std::ffi::OsString,
+    arg: &'a std::ffi::OsStr,
+    cwd: std::borrow::Cow<'a, std::ffi::OsStr>,
+    name: std::ffi::CString,
+    label: Box<std::ffi::CStr>,
+}
+
+#[test]
+fn ffi_strings() {
+    assert_eq!(
+        Process::inline(),
+        "{ program: string, arg: string, cwd: string, name: string, label: string, }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "types/")]
+struct Keymap {
+    leader: char,
+    bindings: std::collections::HashMap<char, String>,
+}
+
+#[cfg(not(feature = "branded-char"))]
+#[test]
+fn char() {
+    assert_eq!(char::name(), "string");
+    #[cfg(not(feature = "js-collections"))]
+    assert_eq!(
+        Keymap::inline(),
+        "{ leader: string, bindings: { [key: string]: string }, }"
+    );
+    assert!(!Keymap::export_to_string().unwrap().contains("import"));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "types/")]
+struct User {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "types/")]
+struct Lookup {
+    result: Result<User, std::convert::Infallible>,
+}
+
+#[test]
+fn infallible() {
+    assert_eq!(std::convert::Infallible::inline(), "never");
+    assert_eq!(
+        Lookup::inline(),
+        r#"{ result: { Ok : User } | { Err : never }, }"#
+    );
+    assert_eq!(
+        Lookup::imports().unwrap(),
+        [r#"import type { User } from "./User";"#]
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "types/")]
+struct Recipients {
+    #[ts(as = "ts_gen::types::OneOrMany<_>")]
+    to: Vec<User>,
+    #[ts(as = "Option<ts_gen::types::OneOrMany<Vec<String>>>")]
+    cc: Option<Vec<String>>,
+}
+
+#[test]
+fn one_or_many() {
+    assert_eq!(
+        Recipients::inline(),
+        "{ to: User | Array<User>, cc: string | Array<string> | null, }"
+    );
+    assert_eq!(
+        Recipients::imports().unwrap(),
+        [r#"import type { User } from "./User";"#]
+    );
+}
@@ -0,0 +1,46 @@
+#![allow(dead_code)]
+
+use std::sync::{OnceLock, RwLock};
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export, export_to = "wrappers/")]
+struct Config {
+    verbose: bool,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "wrappers/")]
+struct State {
+    config: RwLock<Config>,
+    version: OnceLock<String>,
+}
+
+#[test]
+fn locks() {
+    assert_eq!(State::inline(), "{ config: Config, version: string, }");
+    assert_eq!(
+        State::imports().unwrap(),
+        [r#"import type { Config } from "./Config";"#]
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "wrappers/")]
+struct Label {
+    text: Box<str>,
+    tooltip: Option<Box<str>>,
+    aliases: Vec<Box<str>>,
+    shared: std::sync::Arc<str>,
+}
+
+#[test]
+fn boxed_str() {
+    assert_eq!(<Box<str>>::name(), "string");
+    assert_eq!(
+        Label::inline(),
+        "{ text: string, tooltip: string | null, aliases: Array<string>, shared: string, }"
+    );
+    assert!(!Label::export_to_string().unwrap().contains("import"));
+}
@@ -0,0 +1,11 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(enum = "numeric")]
+#[repr(i128)]
+enum Bound {
+    Max = 170141183460469231731687303715884105727,
+    Overflow,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use std::borrow::Cow;
+
+use ts_gen::TS;
+
+#[derive(TS)]
+struct Label<'a> {
+    #[ts(flatten)]
+    text: Cow<'a, str>,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+struct Wrap<T> {
+    #[ts(flatten)]
+    inner: T,
+}
+
+struct NotTs;
+
+fn main() {
+    Wrap::<NotTs>::inline();
+}
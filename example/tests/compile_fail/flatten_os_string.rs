@@ -0,0 +1,11 @@
+use std::ffi::OsString;
+
+use ts_gen::TS;
+
+#[derive(TS)]
+struct Command {
+    #[ts(flatten)]
+    program: OsString,
+}
+
+fn main() {}
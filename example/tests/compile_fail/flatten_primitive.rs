@@ -0,0 +1,9 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+struct Counter {
+    #[ts(flatten)]
+    count: u32,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+struct Style {
+    color: String,
+}
+
+#[derive(TS)]
+#[ts(tag = "kind", hierarchy)]
+enum Shape {
+    Circle {
+        radius: f64,
+        #[ts(flatten)]
+        style: Style,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,33 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(interface)]
+enum Role {
+    Admin,
+    Member,
+}
+
+#[derive(TS)]
+#[ts(interface, type = "string")]
+struct Token {
+    value: String,
+}
+
+#[derive(TS)]
+#[ts(interface)]
+struct Id(u32);
+
+#[derive(TS)]
+struct Inner {
+    a: i32,
+}
+
+#[derive(TS)]
+#[ts(interface)]
+struct Outer {
+    #[ts(flatten)]
+    inner: Inner,
+    b: i32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(namespace = "Api", global)]
+struct Config {
+    debug: bool,
+}
+
+#[derive(TS)]
+#[ts(namespace = "Api::V1")]
+enum Role {
+    Admin,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+struct Point(#[ts(readonly)] i32, i32);
+
+#[derive(TS)]
+#[ts(readonly)]
+struct Pair(String, String);
+
+fn main() {}
@@ -0,0 +1,10 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(result_shape, non_exhaustive)]
+enum Response {
+    Ok(String),
+    Err(String),
+}
+
+fn main() {}
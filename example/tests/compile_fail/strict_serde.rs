@@ -0,0 +1,29 @@
+use serde::Serialize;
+use ts_gen::TS;
+
+#[derive(Serialize, TS)]
+#[ts(strict)]
+struct User {
+    #[serde(serialize_with = "serialize_id")]
+    id: u64,
+}
+
+#[derive(Serialize, TS)]
+#[ts(strict)]
+#[serde(rename_all = "camelCase", transparent)]
+struct Token(String);
+
+#[derive(Serialize, TS)]
+#[ts(strict)]
+enum Event {
+    Click {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        x: Option<i32>,
+    },
+}
+
+fn serialize_id<S: serde::Serializer>(id: &u64, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_str(&id.to_string())
+}
+
+fn main() {}
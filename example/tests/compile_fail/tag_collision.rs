@@ -0,0 +1,23 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(tag = "kind")]
+enum Shape {
+    Circle { radius: f64 },
+    Named { kind: String, sides: u32 },
+}
+
+#[derive(TS)]
+#[ts(tag = "type", rename_all_fields = "camelCase")]
+enum Message {
+    Text { body: String },
+    Typed { r#type: u8 },
+}
+
+#[derive(TS)]
+#[ts(tag = "data", content = "data")]
+enum Packet {
+    Ping,
+}
+
+fn main() {}
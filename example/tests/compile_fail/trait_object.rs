@@ -0,0 +1,10 @@
+use ts_gen::TS;
+
+trait Shape {}
+
+#[derive(TS)]
+struct Canvas {
+    shapes: Vec<Box<dyn Shape>>,
+}
+
+fn main() {}
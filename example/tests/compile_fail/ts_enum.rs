@@ -0,0 +1,33 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(enum)]
+enum Shape {
+    Empty,
+    Circle { radius: f64 },
+}
+
+#[derive(TS)]
+#[ts(enum, tag = "kind")]
+enum Kind {
+    A,
+    B,
+}
+
+const OFFSET: isize = 10;
+
+#[derive(TS)]
+#[ts(enum = "numeric")]
+enum Offset {
+    Start = OFFSET,
+    End,
+}
+
+#[derive(TS)]
+#[ts(enum = "bitflags")]
+enum Flags {
+    A = 1,
+    B = 2,
+}
+
+fn main() {}
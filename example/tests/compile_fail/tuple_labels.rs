@@ -0,0 +1,23 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(tuple_labels = "x, y")]
+struct Point(i32, i32, i32);
+
+#[derive(TS)]
+#[ts(tuple_labels = "first, 2nd")]
+struct Pair(String, String);
+
+#[derive(TS)]
+struct Segment {
+    #[ts(tuple_labels = "start")]
+    range: [u32; 2],
+}
+
+#[derive(TS)]
+struct Label {
+    #[ts(tuple_labels = "text")]
+    text: String,
+}
+
+fn main() {}
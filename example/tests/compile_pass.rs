@@ -0,0 +1,5 @@
+#[test]
+fn compile_pass() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/compile_pass/*.rs");
+}
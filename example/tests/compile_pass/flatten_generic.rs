@@ -0,0 +1,17 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+struct Wrap<T> {
+    #[ts(flatten)]
+    inner: T,
+}
+
+#[derive(TS)]
+struct Inner {
+    a: String,
+}
+
+fn main() {
+    assert_eq!(Wrap::<Inner>::decl(), "type Wrap<T> = T;");
+    assert_eq!(Wrap::<Inner>::inline(), "{ a: string, }");
+}
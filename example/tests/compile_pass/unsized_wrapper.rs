@@ -0,0 +1,30 @@
+use std::{rc::Rc, sync::Arc};
+
+use ts_gen::{typelist::TypeList, TS};
+
+#[derive(TS)]
+struct Samples([f32]);
+
+#[derive(TS)]
+struct Recording {
+    arc: Arc<Samples>,
+    boxed: Box<Samples>,
+    rc: Rc<Samples>,
+    weak: std::sync::Weak<Samples>,
+}
+
+#[derive(TS)]
+struct Shared<T: ?Sized> {
+    inner: Arc<T>,
+}
+
+fn main() {
+    assert_eq!(Arc::<Samples>::name(), "Samples");
+    assert!(<Arc<Samples> as TS>::generics().contains::<Samples>());
+    assert_eq!(
+        Recording::decl(),
+        "type Recording = { arc: Samples, boxed: Samples, rc: Samples, weak: Samples, };"
+    );
+    assert_eq!(Shared::<Samples>::decl(), "type Shared<T> = { inner: T, };");
+    assert_eq!(Shared::<Samples>::inline(), "{ inner: Samples, }");
+}
@@ -0,0 +1,38 @@
+// Runs in its own test binary, since setting `TS_GEN_IMPORT_TYPE` affects the whole process.
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export_to = "import_type/", namespace = "Api")]
+#[allow(dead_code)]
+struct Role {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "import_type/")]
+#[allow(dead_code)]
+struct Session {
+    token: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "import_type/", namespace = "Api")]
+#[allow(dead_code)]
+struct User {
+    role: Role,
+    session: Session,
+}
+
+#[test]
+fn import_type() {
+    std::env::set_var("TS_GEN_IMPORT_TYPE", "true");
+
+    let imports = User::imports().unwrap();
+    assert_eq!(imports.len(), 2);
+    assert!(imports
+        .iter()
+        .all(|import| import.starts_with("import type ")));
+    assert!(imports.contains(&r#"import type { Api as __Api_Role } from "./Role";"#.to_owned()));
+    assert!(imports.contains(&r#"import type { Session } from "./Session";"#.to_owned()));
+}
@@ -0,0 +1,74 @@
+// Runs in its own test binary, since setting `TS_GEN_INDEX` affects the whole process.
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export_to = "models/")]
+#[allow(dead_code)]
+struct Role {
+    name: String,
+}
+
+#[derive(TS)]
+#[allow(dead_code)]
+struct User {
+    role: Role,
+    friends: Vec<User>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "models/")]
+#[allow(dead_code)]
+struct Group {
+    admin: User,
+    members: Vec<User>,
+}
+
+#[test]
+fn index() {
+    std::env::set_var("TS_GEN_INDEX", "true");
+
+    // `index.ts` lists every file within the directory, so it must not be shared with other runs
+    let dir = std::env::temp_dir().join(format!("ts-gen-index-{}", std::process::id()));
+
+    User::export_all_to(&dir).unwrap();
+    Group::export_all_to(&dir).unwrap();
+
+    let index = std::fs::read_to_string(dir.join("index.ts")).unwrap();
+    assert_eq!(
+        index,
+        concat!(
+            "// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n",
+            "export * from \"./User\";\n",
+            "export * from \"./models/Group\";\n",
+            "export * from \"./models/Role\";\n",
+        )
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn index_drops_stale_modules() {
+    std::env::set_var("TS_GEN_INDEX", "true");
+
+    let dir = std::env::temp_dir().join(format!("ts-gen-index-stale-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    // a re-export of a type which has since been renamed or deleted
+    std::fs::write(dir.join("index.ts"), "export * from \"./Old\";\n").unwrap();
+
+    User::export_all_to(&dir).unwrap();
+
+    let index = std::fs::read_to_string(dir.join("index.ts")).unwrap();
+    assert_eq!(
+        index,
+        concat!(
+            "// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n",
+            "export * from \"./User\";\n",
+            "export * from \"./models/Role\";\n",
+        )
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,38 @@
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export_to = "line_ending/")]
+#[allow(dead_code)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "line_ending/")]
+#[allow(dead_code)]
+struct Line {
+    /// The start of the line
+    start: Point,
+    end: Point,
+}
+
+#[test]
+fn crlf() {
+    std::env::set_var("TS_GEN_LINE_ENDING", "crlf");
+
+    let dir = std::env::temp_dir().join(format!("ts-gen-line-ending-{}", std::process::id()));
+    Line::export_all_to(&dir).unwrap();
+
+    let bytes = std::fs::read(dir.join("line_ending/Line.ts")).unwrap();
+    let content = String::from_utf8(bytes).unwrap();
+
+    assert_eq!(
+        content.matches('\n').count(),
+        content.matches("\r\n").count()
+    );
+    assert!(content.contains("\r\nimport type { Point } from \"./Point\";\r\n\r\nexport type Line"));
+    assert!(content.contains("/**\r\n * The start of the line\r\n */\r\n"));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,41 @@
+// Runs in its own test binary, since setting `TS_GEN_QUOTE_STYLE` affects the whole process.
+
+use ts_gen::TS;
+
+#[derive(TS)]
+#[ts(export_to = "quote_style/")]
+#[allow(dead_code)]
+enum Direction {
+    Up,
+    #[ts(rename = "it's down")]
+    Down,
+}
+
+#[derive(TS)]
+#[ts(export_to = "quote_style/", tag = "kind")]
+#[allow(dead_code)]
+struct Move {
+    direction: Direction,
+    #[ts(rename = "step-size")]
+    step_size: bool,
+}
+
+#[test]
+fn single_quotes() {
+    std::env::set_var("TS_GEN_QUOTE_STYLE", "single");
+
+    let exported = Direction::export_to_string().unwrap();
+    assert!(exported.ends_with(r#"export type Direction = 'Up' | 'it\'s down';"#));
+
+    // the quotes are chosen when the bindings are generated, not only when they are exported
+    assert_eq!(Direction::decl(), r#"type Direction = 'Up' | 'it\'s down';"#);
+    assert_eq!(
+        Move::inline(),
+        "{ kind: 'Move', direction: Direction, 'step-size': boolean, }"
+    );
+    assert_eq!(
+        Move::imports().unwrap(),
+        ["import type { Direction } from './Direction';"]
+    );
+    assert_eq!(ts_gen::string_literal("a'b"), r#"'a\'b'"#);
+}
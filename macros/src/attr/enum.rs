@@ -1,9 +1,12 @@
-use syn::{parse_quote, Attribute, Ident, ItemEnum, Path, Result, Type, WherePredicate};
+use syn::{parse_quote, Attribute, Fields, Ident, ItemEnum, Path, Result, Type, WherePredicate};
 
-use super::{parse_assign_from_str, parse_bound, Attr, ContainerAttr};
+use super::{
+    assert_file_name, assert_name_format, assert_namespace, container_name, parse_assign_from_str,
+    parse_assign_path, parse_bound, parse_optional, Attr, ContainerAttr, Optional,
+};
 use crate::{
     attr::{parse_assign_inflection, parse_assign_str, Inflection},
-    utils::{parse_attrs, parse_docs},
+    utils::{append_jsdoc_tag, jsdoc_markdown, parse_attrs, parse_docs},
 };
 
 #[derive(Default)]
@@ -13,14 +16,31 @@ pub struct EnumAttr {
     pub type_override: Option<String>,
     pub rename_all: Option<Inflection>,
     pub rename_all_fields: Option<Inflection>,
+    pub optional: Optional,
     pub rename: Option<String>,
+    pub name_format: Option<String>,
     pub export_to: Option<String>,
+    pub file_name: Option<String>,
     pub export: bool,
+    pub global: bool,
+    pub namespace: Option<String>,
     pub docs: String,
+    pub no_docs: bool,
+    pub jsdoc_markdown: bool,
+    pub strict: bool,
     pub bound: Option<Vec<WherePredicate>>,
     pub tag: Option<String>,
     pub untagged: bool,
+    pub add_discriminant: Option<String>,
     pub content: Option<String>,
+    pub extractors: bool,
+    pub result_shape: bool,
+    pub variants_as_intersection: bool,
+    pub event_map: Option<String>,
+    pub hierarchy: bool,
+    pub example_switch: bool,
+    pub native_enum: Option<NativeEnum>,
+    pub non_exhaustive: bool,
 }
 
 #[derive(Copy, Clone)]
@@ -31,6 +51,15 @@ pub enum Tagged<'a> {
     Untagged,
 }
 
+/// The kind of TypeScript `enum` generated for `#[ts(enum)]`
+#[derive(Copy, Clone)]
+pub enum NativeEnum {
+    /// Members are initialized with the (renamed) name of the variant
+    String,
+    /// Members are initialized with the discriminant of the variant
+    Numeric,
+}
+
 impl EnumAttr {
     pub fn tagged(&self) -> Result<Tagged<'_>> {
         match (self.untagged, &self.tag, &self.content) {
@@ -44,6 +73,11 @@ impl EnumAttr {
         }
     }
 
+    /// The name of the type in TypeScript
+    pub fn ts_name(&self, ident: &Ident) -> String {
+        container_name(ident, self.rename.as_deref(), self.name_format.as_deref())
+    }
+
     pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
         let mut result = parse_attrs::<Self>(attrs)?;
 
@@ -53,8 +87,26 @@ impl EnumAttr {
             result = result.merge(serde_attr.0);
         }
 
-        let docs = parse_docs(attrs)?;
-        result.docs = docs;
+        if attrs.iter().any(|a| a.path().is_ident("non_exhaustive")) {
+            result.non_exhaustive = true;
+        }
+
+        if !result.no_docs {
+            result.docs = parse_docs(attrs)?;
+
+            if let Some(discriminant) = &result.add_discriminant {
+                let remarks = format!(
+                    "@remarks `{}` is synthetic: it is not part of the serialized data, and only \
+                     exists to narrow the union",
+                    discriminant
+                );
+                result.docs = append_jsdoc_tag(&result.docs, &remarks);
+            }
+        }
+
+        if result.jsdoc_markdown {
+            result.docs = jsdoc_markdown(&result.docs);
+        }
 
         Ok(result)
     }
@@ -75,14 +127,32 @@ impl Attr for EnumAttr {
             type_as: self.type_as.or(other.type_as),
             type_override: self.type_override.or(other.type_override),
             rename: self.rename.or(other.rename),
+            name_format: self.name_format.or(other.name_format),
             rename_all: self.rename_all.or(other.rename_all),
             rename_all_fields: self.rename_all_fields.or(other.rename_all_fields),
+            optional: self.optional.or(other.optional),
             tag: self.tag.or(other.tag),
             untagged: self.untagged || other.untagged,
+            add_discriminant: self.add_discriminant.or(other.add_discriminant),
             content: self.content.or(other.content),
+            extractors: self.extractors || other.extractors,
+            result_shape: self.result_shape || other.result_shape,
+            variants_as_intersection: self.variants_as_intersection
+                || other.variants_as_intersection,
+            event_map: self.event_map.or(other.event_map),
+            hierarchy: self.hierarchy || other.hierarchy,
+            example_switch: self.example_switch || other.example_switch,
+            native_enum: self.native_enum.or(other.native_enum),
+            non_exhaustive: self.non_exhaustive || other.non_exhaustive,
             export: self.export || other.export,
+            global: self.global || other.global,
+            namespace: self.namespace.or(other.namespace),
             export_to: self.export_to.or(other.export_to),
+            file_name: self.file_name.or(other.file_name),
             docs: other.docs,
+            no_docs: self.no_docs || other.no_docs,
+            jsdoc_markdown: self.jsdoc_markdown || other.jsdoc_markdown,
+            strict: self.strict || other.strict,
             bound: match (self.bound, other.bound) {
                 (Some(a), Some(b)) => Some(a.into_iter().chain(b).collect()),
                 (Some(bound), None) | (None, Some(bound)) => Some(bound),
@@ -92,6 +162,10 @@ impl Attr for EnumAttr {
     }
 
     fn assert_validity(&self, item: &Self::Item) -> Result<()> {
+        assert_name_format(self.name_format.as_deref())?;
+        assert_file_name(self.export_to.as_deref(), self.file_name.as_deref())?;
+        assert_namespace(self.namespace.as_deref(), self.global)?;
+
         if self.type_override.is_some() {
             if self.type_as.is_some() {
                 syn_err_spanned!(
@@ -134,6 +208,20 @@ impl Attr for EnumAttr {
                     "`untagged` is not compatible with `type`"
                 );
             }
+
+            if self.extractors {
+                syn_err_spanned!(
+                    item;
+                    "`extractors` is not compatible with `type`"
+                );
+            }
+
+            if self.event_map.is_some() {
+                syn_err_spanned!(
+                    item;
+                    "`event_map` is not compatible with `type`"
+                );
+            }
         }
 
         if self.type_as.is_some() {
@@ -171,6 +259,140 @@ impl Attr for EnumAttr {
                     "`untagged` is not compatible with `as`"
                 );
             }
+
+            if self.extractors {
+                syn_err_spanned!(
+                    item;
+                    "`extractors` is not compatible with `as`"
+                );
+            }
+
+            if self.event_map.is_some() {
+                syn_err_spanned!(
+                    item;
+                    "`event_map` is not compatible with `as`"
+                );
+            }
+        }
+
+        if self.extractors && self.untagged {
+            syn_err_spanned!(
+                item;
+                "`extractors` cannot be used with untagged enums"
+            );
+        }
+
+        if self.add_discriminant.is_some() && !self.untagged {
+            syn_err_spanned!(
+                item;
+                "`add_discriminant` can only be used on untagged enums"
+            );
+        }
+
+        if self.variants_as_intersection && (self.tag.is_none() || self.content.is_some()) {
+            syn_err_spanned!(
+                item;
+                "`variants_as_intersection` can only be used on internally tagged enums"
+            );
+        }
+
+        if self.hierarchy && (self.tag.is_none() || self.content.is_some() || self.untagged) {
+            syn_err_spanned!(
+                item;
+                "`hierarchy` can only be used on internally tagged enums"
+            );
+        }
+
+        if self.example_switch && (self.tag.is_none() || self.untagged) {
+            syn_err_spanned!(
+                item;
+                "`example_switch` can only be used on internally or adjacently tagged enums"
+            );
+        }
+
+        if self.hierarchy && (self.type_override.is_some() || self.type_as.is_some()) {
+            syn_err_spanned!(
+                item;
+                "`hierarchy` is not compatible with `type` or `as`"
+            );
+        }
+
+        if self.result_shape {
+            if self.tag.is_some() || self.content.is_some() || self.untagged {
+                syn_err_spanned!(
+                    item;
+                    "`result_shape` is not compatible with `tag`, `content` or `untagged`"
+                );
+            }
+
+            if self.type_override.is_some() || self.type_as.is_some() {
+                syn_err_spanned!(
+                    item;
+                    "`result_shape` is not compatible with `type` or `as`"
+                );
+            }
+
+            if self.event_map.is_some() {
+                syn_err_spanned!(
+                    item;
+                    "`result_shape` is not compatible with `event_map`"
+                );
+            }
+
+            if self.rename_all.is_some() || self.rename_all_fields.is_some() {
+                syn_err_spanned!(
+                    item;
+                    "`result_shape` is not compatible with `rename_all` or `rename_all_fields`"
+                );
+            }
+
+            if self.extractors || self.non_exhaustive {
+                syn_err_spanned!(
+                    item;
+                    "`result_shape` is not compatible with `extractors` or `non_exhaustive`"
+                );
+            }
+        }
+
+        if self.native_enum.is_some() {
+            if self.tag.is_some() || self.content.is_some() || self.untagged {
+                syn_err_spanned!(
+                    item;
+                    "`enum` is not compatible with `tag`, `content` or `untagged`"
+                );
+            }
+
+            if self.type_override.is_some() || self.type_as.is_some() {
+                syn_err_spanned!(
+                    item;
+                    "`enum` is not compatible with `type` or `as`"
+                );
+            }
+
+            if self.result_shape || self.hierarchy {
+                syn_err_spanned!(
+                    item;
+                    "`enum` is not compatible with `result_shape` or `hierarchy`"
+                );
+            }
+
+            if let Some(variant) = item
+                .variants
+                .iter()
+                .find(|v| !matches!(v.fields, Fields::Unit))
+            {
+                syn_err_spanned!(
+                    variant;
+                    "`enum` can only be used on enums whose variants have no fields"
+                );
+            }
+        }
+
+        if self.tag.is_some() && self.tag == self.content {
+            syn_err_spanned!(
+                item;
+                "`tag` and `content` must not be the same"
+            );
         }
 
         match (self.untagged, &self.tag, &self.content) {
@@ -207,14 +429,46 @@ impl_parse! {
         "as" => out.type_as = Some(parse_assign_from_str(input)?),
         "type" => out.type_override = Some(parse_assign_str(input)?),
         "rename" => out.rename = Some(parse_assign_str(input)?),
+        "name_format" => out.name_format = Some(parse_assign_str(input)?),
         "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
         "rename_all_fields" => out.rename_all_fields = Some(parse_assign_inflection(input)?),
-        "export_to" => out.export_to = Some(parse_assign_str(input)?),
+        "optional" => out.optional = parse_optional(input)?,
+        "export_to" => out.export_to = Some(parse_assign_path(input)?),
+        "file_name" => out.file_name = Some(parse_assign_str(input)?),
         "export" => out.export = true,
+        "global" => out.global = true,
+        "namespace" => out.namespace = Some(parse_assign_str(input)?),
         "tag" => out.tag = Some(parse_assign_str(input)?),
         "content" => out.content = Some(parse_assign_str(input)?),
         "untagged" => out.untagged = true,
+        "add_discriminant" => out.add_discriminant = Some(parse_assign_str(input)?),
         "bound" => out.bound = Some(parse_bound(input)?),
+        "no_docs" => out.no_docs = true,
+        "jsdoc_markdown" => out.jsdoc_markdown = true,
+        "strict" => out.strict = true,
+        "extractors" => out.extractors = true,
+        "result_shape" => out.result_shape = true,
+        "variants_as_intersection" => out.variants_as_intersection = true,
+        "event_map" => out.event_map = Some(parse_assign_str(input)?),
+        "hierarchy" => out.hierarchy = true,
+        "example_switch" => out.example_switch = true,
+        "enum" => {
+            use syn::{Token, Error, LitStr};
+            out.native_enum = Some(match input.peek(Token![=]) {
+                true => {
+                    input.parse::<Token![=]>()?;
+                    let kind = input.parse::<LitStr>()?;
+                    match kind.value().as_str() {
+                        "string" => NativeEnum::String,
+                        "numeric" => NativeEnum::Numeric,
+                        _ => Err(Error::new(kind.span(), "expected 'string' or 'numeric'"))?
+                    }
+                }
+                false => NativeEnum::String,
+            })
+        },
+        "interface" => syn_err!(input.span(); "`interface` can only be used on structs, not on enums"),
+        "non_exhaustive" => out.non_exhaustive = true,
     }
 }
 
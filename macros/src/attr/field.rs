@@ -4,8 +4,8 @@ use syn::{
     TypeSlice, TypeTuple,
 };
 
-use super::{parse_assign_from_str, parse_assign_str, Attr};
-use crate::utils::{parse_attrs, parse_docs};
+use super::{parse_assign_from_str, parse_assign_str, parse_tuple_labels, Attr};
+use crate::utils::{append_jsdoc_tag, parse_attrs, parse_docs};
 
 #[derive(Default)]
 pub struct FieldAttr {
@@ -16,6 +16,9 @@ pub struct FieldAttr {
     pub skip: bool,
     pub optional: Optional,
     pub flatten: bool,
+    pub readonly: bool,
+    pub default: Option<String>,
+    pub tuple_labels: Option<Vec<String>>,
     pub docs: String,
 
     #[cfg(feature = "serde-compat")]
@@ -24,11 +27,26 @@ pub struct FieldAttr {
 
 /// Indicates whether the field is marked with `#[ts(optional)]`.
 /// `#[ts(optional)]` turns an `t: Option<T>` into `t?: T`, while
-/// `#[ts(optional = nullable)]` turns it into `t?: T | null`.
-#[derive(Default)]
+/// `#[ts(optional = nullable)]` turns it into `t?: T | null` and
+/// `#[ts(optional = full)]` into `t?: T | null | undefined`.
+///
+/// On a struct or enum, `#[ts(optional)]` applies to every field of type `Option<T>`, unless the
+/// field is marked with `#[ts(optional)]` itself.
+#[derive(Default, Clone, Copy)]
 pub struct Optional {
     pub optional: bool,
     pub nullable: bool,
+    pub undefined: bool,
+}
+
+impl Optional {
+    /// Returns `self` if it is optional, and `other` otherwise
+    pub fn or(self, other: Self) -> Self {
+        match self.optional {
+            true => self,
+            false => other,
+        }
+    }
 }
 
 impl FieldAttr {
@@ -42,6 +60,9 @@ impl FieldAttr {
         }
 
         result.docs = parse_docs(attrs)?;
+        if let Some(default) = &result.default {
+            result.docs = append_jsdoc_tag(&result.docs, &format!("@default {}", default));
+        }
 
         Ok(result)
     }
@@ -69,8 +90,12 @@ impl Attr for FieldAttr {
             optional: Optional {
                 optional: self.optional.optional || other.optional.optional,
                 nullable: self.optional.nullable || other.optional.nullable,
+                undefined: self.optional.undefined || other.optional.undefined,
             },
             flatten: self.flatten || other.flatten,
+            readonly: self.readonly || other.readonly,
+            default: self.default.or(other.default),
+            tuple_labels: self.tuple_labels.or(other.tuple_labels),
             #[cfg(feature = "serde-compat")]
             using_serde_with: self.using_serde_with || other.using_serde_with,
 
@@ -94,6 +119,15 @@ impl Attr for FieldAttr {
             )
         }
 
+        if !self.skip && self.type_override.is_none() && self.type_as.is_none() {
+            if let Some(trait_object) = find_trait_object(&field.ty) {
+                syn_err_spanned!(
+                    trait_object;
+                    r#"trait objects do not implement `TS`. Use `#[ts(type = "..")]` to specify the TypeScript type of this field, or `#[ts(as = "..")]` to use the bindings of another Rust type instead"#
+                )
+            }
+        }
+
         if self.type_override.is_some() {
             if self.type_as.is_some() {
                 syn_err_spanned!(field; "`type` is not compatible with `as`")
@@ -109,6 +143,10 @@ impl Attr for FieldAttr {
                     "`type` is not compatible with `flatten`"
                 );
             }
+
+            if self.tuple_labels.is_some() {
+                syn_err_spanned!(field; "`type` is not compatible with `tuple_labels`")
+            }
         }
 
         if self.flatten {
@@ -139,9 +177,37 @@ impl Attr for FieldAttr {
                     "`optional` is not compatible with `flatten`"
                 );
             }
+
+            if self.default.is_some() {
+                syn_err_spanned!(
+                    field;
+                    "`default` is not compatible with `flatten`"
+                );
+            }
+
+            if self.tuple_labels.is_some() {
+                syn_err_spanned!(
+                    field;
+                    "`tuple_labels` is not compatible with `flatten`"
+                );
+            }
+
+            if let Some(ty) = find_unflattenable(&field.ty) {
+                syn_err_spanned!(
+                    ty;
+                    "`flatten` cannot be used on fields of this type, since it is not an object"
+                );
+            }
         }
 
         if field.ident.is_none() {
+            if self.readonly {
+                syn_err_spanned!(
+                    field;
+                    "`readonly` cannot be used on tuple struct fields"
+                );
+            }
+
             if self.flatten {
                 syn_err_spanned!(
                     field;
@@ -162,6 +228,13 @@ impl Attr for FieldAttr {
                     "`optional` cannot with tuple struct fields"
                 );
             }
+
+            if self.default.is_some() {
+                syn_err_spanned!(
+                    field;
+                    "`default` cannot be used on tuple struct fields"
+                );
+            }
         }
 
         Ok(())
@@ -175,27 +248,42 @@ impl_parse! {
         "rename" => out.rename = Some(parse_assign_str(input)?),
         "inline" => out.inline = true,
         "skip" => out.skip = true,
-        "optional" => {
-            use syn::{Token, Error};
-            let nullable = if input.peek(Token![=]) {
-                input.parse::<Token![=]>()?;
-                let span = input.span();
-                match Ident::parse(input)?.to_string().as_str() {
-                    "nullable" => true,
-                    _ => Err(Error::new(span, "expected 'nullable'"))?
-                }
-            } else {
-                false
-            };
-            out.optional = Optional {
-                optional: true,
-                nullable,
-            }
-        },
+        "optional" => out.optional = parse_optional(input)?,
         "flatten" => out.flatten = true,
+        "readonly" => out.readonly = true,
+        "default" => out.default = Some(parse_assign_str(input)?),
+        "tuple_labels" => out.tuple_labels = Some(parse_tuple_labels(input)?),
     }
 }
 
+/// Parses `optional`, `optional = nullable` or `optional = full`, after `optional` itself has
+/// already been consumed
+pub(super) fn parse_optional(input: syn::parse::ParseStream) -> Result<Optional> {
+    use syn::{parse::Parse, Error, LitStr, Token};
+
+    let (nullable, undefined) = if input.peek(Token![=]) {
+        input.parse::<Token![=]>()?;
+        let span = input.span();
+        let mode = match input.peek(LitStr) {
+            true => input.parse::<LitStr>()?.value(),
+            false => Ident::parse(input)?.to_string(),
+        };
+        match mode.as_str() {
+            "nullable" => (true, false),
+            "full" => (true, true),
+            _ => return Err(Error::new(span, "expected 'nullable' or 'full'")),
+        }
+    } else {
+        (false, false)
+    };
+
+    Ok(Optional {
+        optional: true,
+        nullable,
+        undefined,
+    })
+}
+
 #[cfg(feature = "serde-compat")]
 use super::Serde;
 
@@ -219,6 +307,92 @@ impl_parse! {
     }
 }
 
+// Returns the first trait object (`dyn Trait`) found within the given type
+fn find_trait_object(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::TraitObject(_) => Some(ty),
+        Type::Array(TypeArray { elem, .. })
+        | Type::Group(TypeGroup { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Ptr(TypePtr { elem, .. })
+        | Type::Reference(TypeReference { elem, .. })
+        | Type::Slice(TypeSlice { elem, .. }) => find_trait_object(elem),
+        Type::Tuple(TypeTuple { elems, .. }) => elems.iter().find_map(find_trait_object),
+        Type::Path(TypePath { path, .. }) => {
+            path.segments
+                .iter()
+                .find_map(|segment| match &segment.arguments {
+                    PathArguments::AngleBracketed(a) => a.args.iter().find_map(|arg| match arg {
+                        GenericArgument::Type(ty) => find_trait_object(ty),
+                        _ => None,
+                    }),
+                    _ => None,
+                })
+        }
+        _ => None,
+    }
+}
+
+// Returns the type which prevents the field of type `ty` from being flattened, if any.
+// Since the shape of most types is unknown here, this only catches primitives, tuples and arrays,
+// possibly behind a reference or a smart pointer.
+fn find_unflattenable(ty: &Type) -> Option<&Type> {
+    const PRIMITIVES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+        "f32", "f64", "bool", "char", "str", "String", "OsStr", "OsString", "CStr", "CString",
+    ];
+
+    match ty {
+        Type::Array(_) | Type::Slice(_) | Type::Tuple(_) => Some(ty),
+        Type::Group(TypeGroup { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Reference(TypeReference { elem, .. }) => find_unflattenable(elem),
+        Type::Path(TypePath { qself: None, path }) => {
+            let last = path.segments.last()?;
+            if last.arguments.is_none() && PRIMITIVES.iter().any(|p| last.ident == p) {
+                return Some(ty);
+            }
+
+            // smart pointers are flattened like the type they point to, e.g. `Cow<'_, str>`
+            pointee(ty).and_then(find_unflattenable).map(|_| ty)
+        }
+        _ => None,
+    }
+}
+
+// Returns the element type `T` if `ty` is a slice `[T]`, possibly behind a reference or a smart
+// pointer like `Box<[T]>`
+pub fn slice_element(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::Slice(TypeSlice { elem, .. }) => Some(elem),
+        Type::Group(TypeGroup { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Reference(TypeReference { elem, .. }) => slice_element(elem),
+        _ => pointee(ty).and_then(slice_element),
+    }
+}
+
+// Returns `T` if `ty` is a smart pointer to `T`, like `Box<T>` or `Cow<'_, T>`
+fn pointee(ty: &Type) -> Option<&Type> {
+    const POINTERS: &[&str] = &["Box", "Arc", "Rc", "Cow"];
+
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+    let last = path.segments.last()?;
+    if !POINTERS.iter().any(|p| last.ident == p) {
+        return None;
+    }
+
+    match &last.arguments {
+        PathArguments::AngleBracketed(a) => a.args.iter().find_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
 fn replace_underscore(ty: &mut Type, with: &Type) {
     match ty {
         Type::Infer(_) => *ty = with.clone(),
@@ -269,7 +443,7 @@ fn replace_underscore_in_angle_bracketed(args: &mut AngleBracketedGenericArgumen
             }
             GenericArgument::AssocType(assoc_ty) => {
                 replace_underscore(&mut assoc_ty.ty, with);
-                for g in &mut assoc_ty.generics {
+                if let Some(g) = &mut assoc_ty.generics {
                     replace_underscore_in_angle_bracketed(g, with);
                 }
             }
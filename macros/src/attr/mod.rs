@@ -1,9 +1,11 @@
 use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
-    Error, Lit, Path, Result, Token, WherePredicate,
+    Error, Ident, Lit, Path, Result, Token, WherePredicate,
 };
 
+use crate::utils::{is_ts_field, to_ts_ident};
+
 mod r#enum;
 mod field;
 mod r#struct;
@@ -100,6 +102,97 @@ impl Inflection {
     }
 }
 
+/// Computes the TypeScript name of a container from its identifier.
+/// An explicit `rename` takes precedence over `name_format`, in which `{}` is replaced by the
+/// identifier.
+fn container_name(ident: &Ident, rename: Option<&str>, name_format: Option<&str>) -> String {
+    match (rename, name_format) {
+        (Some(rename), _) => rename.to_owned(),
+        (None, Some(format)) => format.replace("{}", &to_ts_ident(ident)),
+        (None, None) => to_ts_ident(ident),
+    }
+}
+
+fn assert_name_format(name_format: Option<&str>) -> Result<()> {
+    match name_format {
+        Some(format) if !format.contains("{}") => {
+            syn_err!("`name_format` must contain `{{}}`, which is replaced by the name of the type")
+        }
+        _ => Ok(()),
+    }
+}
+
+fn assert_file_name(export_to: Option<&str>, file_name: Option<&str>) -> Result<()> {
+    match (export_to, file_name) {
+        (Some(export_to), Some(_)) if !export_to.ends_with('/') => syn_err!(
+            "`file_name` cannot be used if `export_to` is a file, only if it is a directory ending in `/`"
+        ),
+        _ => Ok(()),
+    }
+}
+
+fn assert_namespace(namespace: Option<&str>, global: bool) -> Result<()> {
+    let Some(namespace) = namespace else {
+        return Ok(());
+    };
+
+    if global {
+        syn_err!("`namespace` is not compatible with `global`");
+    }
+
+    if !namespace
+        .split('.')
+        .all(|segment| !segment.is_empty() && is_ts_field(segment))
+    {
+        syn_err!("`namespace` must be an identifier, or multiple identifiers separated by `.`");
+    }
+
+    Ok(())
+}
+
+/// With `#[ts(strict)]`, serde attributes on a struct and its fields which ts-gen fails to parse
+/// are an error instead of a warning.
+#[cfg(feature = "serde-compat")]
+pub fn assert_strict_struct(s: &syn::ItemStruct) -> Result<()> {
+    assert_serde_attrs::<StructAttr>(&s.attrs)?;
+    assert_strict_fields(&s.fields)
+}
+
+/// With `#[ts(strict)]`, serde attributes on an enum, its variants and their fields which ts-gen
+/// fails to parse are an error instead of a warning.
+#[cfg(feature = "serde-compat")]
+pub fn assert_strict_enum(e: &syn::ItemEnum) -> Result<()> {
+    assert_serde_attrs::<EnumAttr>(&e.attrs)?;
+    for variant in &e.variants {
+        assert_serde_attrs::<VariantAttr>(&variant.attrs)?;
+        assert_strict_fields(&variant.fields)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "serde-compat")]
+fn assert_strict_fields(fields: &syn::Fields) -> Result<()> {
+    fields
+        .iter()
+        .try_for_each(|field| assert_serde_attrs::<FieldAttr>(&field.attrs))
+}
+
+#[cfg(feature = "serde-compat")]
+fn assert_serde_attrs<T>(attrs: &[syn::Attribute]) -> Result<()>
+where
+    T: Attr,
+    for<'a> Serde<T>: TryFrom<&'a syn::Attribute, Error = Error>,
+{
+    for attr in attrs.iter().filter(|a| a.path().is_ident("serde")) {
+        if let Err(err) = Serde::<T>::try_from(attr) {
+            syn_err!(err.span(); "unsupported serde attribute with `strict`: {}", err);
+        }
+    }
+
+    Ok(())
+}
+
 fn parse_assign_str(input: ParseStream) -> Result<String> {
     input.parse::<Token![=]>()?;
     match Lit::parse(input)? {
@@ -108,6 +201,12 @@ fn parse_assign_str(input: ParseStream) -> Result<String> {
     }
 }
 
+// parses the path given with `#[ts(export_to = "..")]`, which may also use `\` as separator.
+// Since `\` is not a separator on all platforms, it is replaced with `/`.
+fn parse_assign_path(input: ParseStream) -> Result<String> {
+    Ok(parse_assign_str(input)?.replace('\\', "/"))
+}
+
 fn parse_assign_inflection(input: ParseStream) -> Result<Inflection> {
     input.parse::<Token![=]>()?;
 
@@ -143,6 +242,29 @@ where
     }
 }
 
+fn parse_tuple_labels(input: ParseStream) -> Result<Vec<String>> {
+    input.parse::<Token![=]>()?;
+    match Lit::parse(input)? {
+        Lit::Str(string) => {
+            let labels = string
+                .value()
+                .split(',')
+                .map(|label| label.trim().to_owned())
+                .collect::<Vec<_>>();
+
+            if !labels
+                .iter()
+                .all(|label| !label.is_empty() && is_ts_field(label))
+            {
+                syn_err!(string.span(); "`tuple_labels` must be a comma-separated list of identifiers");
+            }
+
+            Ok(labels)
+        }
+        other => Err(Error::new(other.span(), "expected string")),
+    }
+}
+
 fn parse_bound(input: ParseStream) -> Result<Vec<WherePredicate>> {
     input.parse::<Token![=]>()?;
     match Lit::parse(input)? {
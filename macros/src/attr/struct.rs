@@ -1,9 +1,13 @@
 use syn::{parse_quote, Attribute, Fields, Ident, Path, Result, Type, WherePredicate};
 
-use super::{parse_assign_from_str, parse_assign_inflection, parse_bound, Attr, ContainerAttr};
+use super::{
+    assert_file_name, assert_name_format, assert_namespace, container_name, parse_assign_from_str,
+    parse_assign_inflection, parse_assign_path, parse_bound, parse_optional, parse_tuple_labels,
+    Attr, ContainerAttr, Optional,
+};
 use crate::{
-    attr::{parse_assign_str, EnumAttr, Inflection, VariantAttr},
-    utils::{parse_attrs, parse_docs},
+    attr::{parse_assign_str, EnumAttr, FieldAttr, Inflection, VariantAttr},
+    utils::{jsdoc_markdown, parse_attrs, parse_docs},
 };
 
 #[derive(Default, Clone)]
@@ -13,10 +17,24 @@ pub struct StructAttr {
     pub type_override: Option<String>,
     pub rename_all: Option<Inflection>,
     pub rename: Option<String>,
+    pub name_format: Option<String>,
     pub export_to: Option<String>,
+    pub file_name: Option<String>,
     pub export: bool,
+    pub global: bool,
+    pub namespace: Option<String>,
     pub tag: Option<String>,
+    pub readonly: bool,
+    pub readonly_deep: bool,
+    pub transparent: bool,
+    pub field_names_union: bool,
+    pub interface: bool,
+    pub tuple_labels: Option<Vec<String>>,
+    pub optional: Optional,
     pub docs: String,
+    pub no_docs: bool,
+    pub jsdoc_markdown: bool,
+    pub strict: bool,
     pub bound: Option<Vec<WherePredicate>>,
 }
 
@@ -30,12 +48,22 @@ impl StructAttr {
             result = result.merge(serde_attr.0);
         }
 
-        let docs = parse_docs(attrs)?;
-        result.docs = docs;
+        if !result.no_docs {
+            result.docs = parse_docs(attrs)?;
+        }
+
+        if result.jsdoc_markdown {
+            result.docs = jsdoc_markdown(&result.docs);
+        }
 
         Ok(result)
     }
 
+    /// The name of the type in TypeScript
+    pub fn ts_name(&self, ident: &Ident) -> String {
+        container_name(ident, self.rename.as_deref(), self.name_format.as_deref())
+    }
+
     pub fn from_variant(
         enum_attr: &EnumAttr,
         variant_attr: &VariantAttr,
@@ -44,6 +72,9 @@ impl StructAttr {
         Self {
             crate_rename: Some(enum_attr.crate_rename()),
             rename: variant_attr.rename.clone(),
+            no_docs: enum_attr.no_docs,
+            jsdoc_markdown: enum_attr.jsdoc_markdown,
+            optional: enum_attr.optional,
             rename_all: variant_attr.rename_all.or(match variant_fields {
                 Fields::Named(_) => enum_attr.rename_all_fields,
                 Fields::Unnamed(_) | Fields::Unit => None,
@@ -63,11 +94,25 @@ impl Attr for StructAttr {
             type_as: self.type_as.or(other.type_as),
             type_override: self.type_override.or(other.type_override),
             rename: self.rename.or(other.rename),
+            name_format: self.name_format.or(other.name_format),
             rename_all: self.rename_all.or(other.rename_all),
             export_to: self.export_to.or(other.export_to),
+            file_name: self.file_name.or(other.file_name),
             export: self.export || other.export,
+            global: self.global || other.global,
+            namespace: self.namespace.or(other.namespace),
             tag: self.tag.or(other.tag),
+            readonly: self.readonly || other.readonly,
+            readonly_deep: self.readonly_deep || other.readonly_deep,
+            transparent: self.transparent || other.transparent,
+            field_names_union: self.field_names_union || other.field_names_union,
+            interface: self.interface || other.interface,
+            tuple_labels: self.tuple_labels.or(other.tuple_labels),
+            optional: self.optional.or(other.optional),
             docs: other.docs,
+            no_docs: self.no_docs || other.no_docs,
+            jsdoc_markdown: self.jsdoc_markdown || other.jsdoc_markdown,
+            strict: self.strict || other.strict,
             bound: match (self.bound, other.bound) {
                 (Some(a), Some(b)) => Some(a.into_iter().chain(b).collect()),
                 (Some(bound), None) | (None, Some(bound)) => Some(bound),
@@ -77,6 +122,10 @@ impl Attr for StructAttr {
     }
 
     fn assert_validity(&self, item: &Self::Item) -> Result<()> {
+        assert_name_format(self.name_format.as_deref())?;
+        assert_file_name(self.export_to.as_deref(), self.file_name.as_deref())?;
+        assert_namespace(self.namespace.as_deref(), self.global)?;
+
         if self.type_override.is_some() {
             if self.type_as.is_some() {
                 syn_err!("`as` is not compatible with `type`");
@@ -89,6 +138,22 @@ impl Attr for StructAttr {
             if self.tag.is_some() {
                 syn_err!("`tag` is not compatible with `type`");
             }
+
+            if self.readonly {
+                syn_err!("`readonly` is not compatible with `type`");
+            }
+
+            if self.readonly_deep {
+                syn_err!("`readonly_deep` is not compatible with `type`");
+            }
+
+            if self.transparent {
+                syn_err!("`transparent` is not compatible with `type`");
+            }
+
+            if self.field_names_union {
+                syn_err!("`field_names_union` is not compatible with `type`");
+            }
         }
 
         if self.type_as.is_some() {
@@ -99,6 +164,97 @@ impl Attr for StructAttr {
             if self.rename_all.is_some() {
                 syn_err!("`rename_all` is not compatible with `as`");
             }
+
+            if self.readonly {
+                syn_err!("`readonly` is not compatible with `as`");
+            }
+
+            if self.readonly_deep {
+                syn_err!("`readonly_deep` is not compatible with `as`");
+            }
+
+            if self.transparent {
+                syn_err!("`transparent` is not compatible with `as`");
+            }
+
+            if self.field_names_union {
+                syn_err!("`field_names_union` is not compatible with `as`");
+            }
+        }
+
+        if self.transparent {
+            if item.len() != 1 {
+                syn_err!("`transparent` requires a struct with exactly one field");
+            }
+
+            if self.tag.is_some() {
+                syn_err!("`tag` is not compatible with `transparent`");
+            }
+
+            if self.field_names_union {
+                syn_err!("`field_names_union` is not compatible with `transparent`");
+            }
+        }
+
+        if self.interface {
+            if self.type_override.is_some() {
+                syn_err!("`interface` is not compatible with `type`");
+            }
+
+            if self.type_as.is_some() {
+                syn_err!("`interface` is not compatible with `as`");
+            }
+
+            if self.transparent {
+                syn_err!("`interface` is not compatible with `transparent`");
+            }
+
+            if self.readonly_deep {
+                syn_err!("`interface` is not compatible with `readonly_deep`");
+            }
+
+            if !matches!(item, Fields::Named(named) if !named.named.is_empty()) {
+                syn_err!("`interface` can only be used on structs with named fields");
+            }
+
+            for field in item {
+                if FieldAttr::from_attrs(&field.attrs)?.flatten {
+                    syn_err_spanned!(field; "`flatten` cannot be used within an `interface`");
+                }
+            }
+        }
+
+        if let Some(labels) = &self.tuple_labels {
+            if self.type_override.is_some() {
+                syn_err!("`tuple_labels` is not compatible with `type`");
+            }
+
+            if self.type_as.is_some() {
+                syn_err!("`tuple_labels` is not compatible with `as`");
+            }
+
+            if self.transparent {
+                syn_err!("`tuple_labels` is not compatible with `transparent`");
+            }
+
+            let Fields::Unnamed(unnamed) = item else {
+                syn_err!("`tuple_labels` can only be used on tuple structs");
+            };
+
+            let mut arity = 0;
+            for field in &unnamed.unnamed {
+                if !FieldAttr::from_attrs(&field.attrs)?.skip {
+                    arity += 1;
+                }
+            }
+            if labels.len() != arity {
+                syn_err_spanned!(
+                    unnamed;
+                    "`tuple_labels` expects {} labels, one for each field, but {} were given",
+                    arity,
+                    labels.len()
+                );
+            }
         }
 
         if !matches!(item, Fields::Named(_)) {
@@ -109,6 +265,14 @@ impl Attr for StructAttr {
             if self.rename_all.is_some() {
                 syn_err!("`rename_all` cannot be used with unit or tuple structs");
             }
+
+            if self.field_names_union {
+                syn_err!("`field_names_union` cannot be used with unit or tuple structs");
+            }
+
+            if self.readonly {
+                syn_err!("`readonly` cannot be used with unit or tuple structs");
+            }
         }
 
         Ok(())
@@ -129,11 +293,25 @@ impl_parse! {
         "as" => out.type_as = Some(parse_assign_from_str(input)?),
         "type" => out.type_override = Some(parse_assign_str(input)?),
         "rename" => out.rename = Some(parse_assign_str(input)?),
+        "name_format" => out.name_format = Some(parse_assign_str(input)?),
         "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
         "tag" => out.tag = Some(parse_assign_str(input)?),
         "export" => out.export = true,
-        "export_to" => out.export_to = Some(parse_assign_str(input)?),
+        "global" => out.global = true,
+        "namespace" => out.namespace = Some(parse_assign_str(input)?),
+        "export_to" => out.export_to = Some(parse_assign_path(input)?),
+        "file_name" => out.file_name = Some(parse_assign_str(input)?),
         "bound" => out.bound = Some(parse_bound(input)?),
+        "no_docs" => out.no_docs = true,
+        "jsdoc_markdown" => out.jsdoc_markdown = true,
+        "strict" => out.strict = true,
+        "readonly" => out.readonly = true,
+        "readonly_deep" => out.readonly_deep = true,
+        "transparent" => out.transparent = true,
+        "field_names_union" => out.field_names_union = true,
+        "interface" => out.interface = true,
+        "tuple_labels" => out.tuple_labels = Some(parse_tuple_labels(input)?),
+        "optional" => out.optional = parse_optional(input)?,
     }
 }
 
@@ -31,9 +31,37 @@ struct DerivedTS {
     inline_flattened: Option<TokenStream>,
     dependencies: Dependencies,
     bound: Option<Vec<WherePredicate>>,
+    /// Expressions evaluating to additional declarations emitted alongside the type.
+    /// Within them, `name` refers to the name of the type including its generic parameters, and
+    /// `generics` to its formatted generic parameters (see `format_generics`).
+    helper_decls: Vec<TokenStream>,
 
     export: bool,
     export_to: Option<String>,
+    /// The name of the file, without the `.ts` extension, if it differs from `ts_name`
+    file_name: Option<String>,
+    global: bool,
+    /// The TypeScript namespace the type is declared in, see `#[ts(namespace = "..")]`
+    namespace: Option<String>,
+    /// Whether the type is a union of string or number literals, see `TS::LITERAL_UNION`
+    literal_union: bool,
+    decl_kind: DeclKind,
+}
+
+/// How the declaration of a type is emitted
+#[derive(Debug)]
+enum DeclKind {
+    /// `type Name = ..;`
+    TypeAlias,
+    /// `type Name = ..;`, using the given definition instead of `inline`, since it refers to the
+    /// helper declarations, see `#[ts(variants_as_intersection)]`.
+    TypeAliasOf(TokenStream),
+    /// `interface Name { .. }`, see `#[ts(interface)]`
+    Interface,
+    /// `enum Name { Member = value, .. }`, see `#[ts(enum)]`.
+    /// Contains the name of every member, which may be preceded by its JSDoc, together with an
+    /// expression producing its value as a TypeScript literal.
+    Enum(Vec<(String, TokenStream)>),
 }
 
 impl DerivedTS {
@@ -42,18 +70,37 @@ impl DerivedTS {
         let export = allow_export.then(|| self.generate_export_test(&rust_ty, &generics));
 
         let output_path_fn = {
+            let file_name = self.file_name.as_deref().unwrap_or(&self.ts_name);
             let path = match self.export_to.as_deref() {
                 Some(dirname) if dirname.ends_with('/') => {
-                    format!("{}{}.ts", dirname, self.ts_name)
+                    format!("{}{}.ts", dirname, file_name)
                 }
                 Some(filename) => filename.to_owned(),
-                None => format!("{}.ts", self.ts_name),
+                None => format!("{}.ts", file_name),
             };
 
-            quote! {
-                fn output_path() -> Option<&'static std::path::Path> {
-                    Some(std::path::Path::new(#path))
-                }
+            match self.export_to {
+                // the module path, without the name of the crate, becomes the directory of the file
+                None if cfg!(feature = "export-module-path") => quote! {
+                    fn output_path() -> Option<&'static std::path::Path> {
+                        static PATH: std::sync::OnceLock<std::path::PathBuf> =
+                            std::sync::OnceLock::new();
+
+                        Some(PATH.get_or_init(|| {
+                            let mut path = module_path!()
+                                .split("::")
+                                .skip(1)
+                                .collect::<std::path::PathBuf>();
+                            path.push(#path);
+                            path
+                        }))
+                    }
+                },
+                _ => quote! {
+                    fn output_path() -> Option<&'static std::path::Path> {
+                        Some(std::path::Path::new(#path))
+                    }
+                },
             }
         };
 
@@ -62,6 +109,24 @@ impl DerivedTS {
             docs => Some(quote!(const DOCS: Option<&'static str> = Some(#docs);)),
         };
 
+        let global = self.global.then(|| {
+            quote!(
+                const GLOBAL: bool = true;
+            )
+        });
+
+        let namespace = self.namespace.as_ref().map(|namespace| {
+            quote!(
+                const NAMESPACE: Option<&'static str> = Some(#namespace);
+            )
+        });
+
+        let literal_union = self.literal_union.then(|| {
+            quote!(
+                const LITERAL_UNION: bool = true;
+            )
+        });
+
         let crate_rename = self.crate_rename.clone();
 
         let ident = self.ts_name.clone();
@@ -75,6 +140,7 @@ impl DerivedTS {
         let name = self.generate_name_fn(&generics);
         let inline = self.generate_inline_fn();
         let decl = self.generate_decl_fn(&rust_ty, &generics);
+        let helper_decls = self.generate_helper_decls_fn(&rust_ty, &generics);
         let dependencies = &self.dependencies;
         let generics_fn = self.generate_generics_fn(&generics);
 
@@ -86,11 +152,15 @@ impl DerivedTS {
                 }
 
                 #docs
+                #global
+                #namespace
+                #literal_union
                 #name
                 #decl
                 #inline
                 #generics_fn
                 #output_path_fn
+                #helper_decls
 
                 #[allow(clippy::unused_unit)]
                 fn dependency_types() -> impl #crate_rename::typelist::TypeList
@@ -108,7 +178,11 @@ impl DerivedTS {
     /// Returns an expression which evaluates to the TypeScript name of the type, including generic
     /// parameters.
     fn name_with_generics(&self, generics: &Generics) -> TokenStream {
-        let name = &self.ts_name;
+        // outside of its declaration, a type in a namespace is referred to by its qualified name
+        let name = match &self.namespace {
+            Some(namespace) => format!("{}.{}", namespace, self.ts_name),
+            None => self.ts_name.clone(),
+        };
         let crate_rename = &self.crate_rename;
         let mut generics_ts_names = generics
             .type_params()
@@ -186,7 +260,7 @@ impl DerivedTS {
 
         let mut results = vec![];
         for g in generics {
-            let g_traits = traits.get(&g).cloned().unwrap_or_else(|| vec![]);
+            let g_traits = traits.get(&g).cloned().unwrap_or_default();
             let res = quote! {
                 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, Ord, PartialOrd, #(#g_traits,)*)]
                 struct #g;
@@ -198,7 +272,7 @@ impl DerivedTS {
                 impl #crate_rename::TS for #g {
                     fn name() -> String { stringify!(#g).to_owned() }
                     fn inline() -> String { panic!("{} cannot be inlined", #name) }
-                    fn inline_flattened() -> String { panic!("{} cannot be flattened", #name) }
+                    fn inline_flattened() -> String { stringify!(#g).to_owned() }
                     fn decl() -> String { panic!("{} cannot be declared", #name) }
                     fn decl_concrete() -> String { panic!("{} cannot be declared", #name) }
                 }
@@ -292,34 +366,122 @@ impl DerivedTS {
         }
     }
 
-    /// Generates the `decl()` and `decl_concrete()` methods.
+    /// Generates the `decl()`, `decl_named()` and `decl_concrete()` methods.
     /// `decl_concrete()` is simple, and simply defers to `inline()`.
-    /// For `decl()`, however, we need to change out the generic parameters of the type, replacing
-    /// them with the dummy types generated by `generate_generic_types()`.
+    /// For `decl_named()`, however, we need to change out the generic parameters of the type,
+    /// replacing them with the dummy types generated by `generate_generic_types()`.
+    /// `decl()` then defers to `decl_named()`, using the name of the type.
     fn generate_decl_fn(&mut self, rust_ty: &Ident, generics: &Generics) -> TokenStream {
         let name = &self.ts_name;
         let crate_rename = &self.crate_rename;
         let ts_generics = format_generics(&mut self.dependencies, crate_rename, generics);
+        let generic_types = self.generate_generic_types(generics);
 
-        let generic_idents = filter_generic_params(&generics);
+        let generic_idents = filter_generic_params(generics);
+
+        match &self.decl_kind {
+            DeclKind::TypeAlias => (),
+            DeclKind::TypeAliasOf(definition) => {
+                return quote! {
+                    fn decl_concrete() -> String {
+                        format!("type {} = {};", #name, #definition)
+                    }
+                    fn decl() -> String {
+                        <Self as #crate_rename::TS>::decl_named(#name)
+                    }
+                    fn decl_named(name: &str) -> String {
+                        #generic_types
+                        let definition = #definition;
+                        let generics = #ts_generics;
+                        format!("type {name}{generics} = {definition};")
+                    }
+                };
+            }
+            DeclKind::Interface => {
+                return quote! {
+                    fn decl_concrete() -> String {
+                        format!("interface {} {}", #name, <Self as #crate_rename::TS>::inline())
+                    }
+                    fn decl() -> String {
+                        <Self as #crate_rename::TS>::decl_named(#name)
+                    }
+                    fn decl_named(name: &str) -> String {
+                        #generic_types
+                        let inline = <#rust_ty<#(#generic_idents,)*> as #crate_rename::TS>::inline();
+                        let generics = #ts_generics;
+                        format!("interface {name}{generics} {inline}")
+                    }
+                };
+            }
+            DeclKind::Enum(members) => {
+                let members = members
+                    .iter()
+                    .map(|(member, value)| quote!(format!("{} = {}", #member, #value)));
+                return quote! {
+                    fn decl_concrete() -> String {
+                        <Self as #crate_rename::TS>::decl_named(#name)
+                    }
+                    fn decl() -> String {
+                        <Self as #crate_rename::TS>::decl_named(#name)
+                    }
+                    fn decl_named(name: &str) -> String {
+                        format!("enum {} {{ {} }}", name, [#(#members),*].join(", "))
+                    }
+                };
+            }
+        }
 
         quote! {
             fn decl_concrete() -> String {
                 format!("type {} = {};", #name, <Self as #crate_rename::TS>::inline())
             }
             fn decl() -> String {
+                <Self as #crate_rename::TS>::decl_named(#name)
+            }
+            fn decl_named(name: &str) -> String {
+                #generic_types
                 let inline = <#rust_ty<#(#generic_idents,)*> as #crate_rename::TS>::inline();
                 let generics = #ts_generics;
-                format!("type {}{generics} = {inline};", #name)
+                format!("type {name}{generics} = {inline};")
             }
         }
     }
+
+    /// Generates the `helper_decls()` method, if the type has any helper declarations.
+    /// Just like in `decl()`, the generic parameters are swapped for the dummy types generated by
+    /// `generate_generic_types()`.
+    fn generate_helper_decls_fn(
+        &mut self,
+        rust_ty: &Ident,
+        generics: &Generics,
+    ) -> Option<TokenStream> {
+        if self.helper_decls.is_empty() {
+            return None;
+        }
+
+        let crate_rename = &self.crate_rename;
+        let ts_generics = format_generics(&mut self.dependencies, crate_rename, generics);
+        let generic_types = self.generate_generic_types(generics);
+        let generic_idents = filter_generic_params(generics);
+        let helper_decls = &self.helper_decls;
+
+        Some(quote! {
+            #[allow(unused_variables)]
+            fn helper_decls() -> Vec<String> {
+                #generic_types
+                let name = <#rust_ty<#(#generic_idents,)*> as #crate_rename::TS>::name();
+                let generics = #ts_generics;
+                vec![#(#helper_decls),*]
+            }
+        })
+    }
 }
 
+type GenericParams<'a> =
+    FilterMap<Iter<'a, GenericParam>, fn(&GenericParam) -> Option<TokenStream>>;
+
 /// These are the generic parameters we'll be using.
-fn filter_generic_params(
-    generics: &Generics,
-) -> FilterMap<Iter<GenericParam>, fn(&GenericParam) -> Option<TokenStream>> {
+fn filter_generic_params(generics: &Generics) -> GenericParams<'_> {
     generics.params.iter().filter_map(|p| match p {
         GenericParam::Lifetime(_) => None,
         GenericParam::Type(TypeParam { ident, .. }) => Some(quote!(#ident)),
@@ -1,25 +1,28 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Fields, ItemEnum, Variant};
+use syn::{Expr, ExprLit, ExprUnary, Fields, Generics, ItemEnum, Lit, UnOp, Variant};
 
 use crate::{
-    attr::{Attr, EnumAttr, FieldAttr, StructAttr, Tagged, VariantAttr},
+    attr::{Attr, EnumAttr, FieldAttr, NativeEnum, StructAttr, Tagged, VariantAttr},
     deps::Dependencies,
-    types::{self, type_as, type_override},
-    DerivedTS,
+    types::{self, named, type_as, type_override},
+    utils::{is_ts_field, parse_deprecated, raw_name_to_ts_field, string_literal, to_ts_ident},
+    DeclKind, DerivedTS,
 };
 
 pub(crate) fn r#enum_def(s: &ItemEnum) -> syn::Result<DerivedTS> {
-    let enum_attr: EnumAttr = EnumAttr::from_attrs(&s.attrs)?;
+    let mut enum_attr: EnumAttr = EnumAttr::from_attrs(&s.attrs)?;
 
     enum_attr.assert_validity(s)?;
 
+    #[cfg(feature = "serde-compat")]
+    if enum_attr.strict {
+        crate::attr::assert_strict_enum(s)?;
+    }
+
     let crate_rename = enum_attr.crate_rename();
 
-    let name = match &enum_attr.rename {
-        Some(existing) => existing.clone(),
-        None => s.ident.to_string(),
-    };
+    let name = enum_attr.ts_name(&s.ident);
 
     if let Some(attr_type_override) = &enum_attr.type_override {
         return type_override::type_override_enum(&enum_attr, &name, attr_type_override);
@@ -42,21 +45,108 @@ pub(crate) fn r#enum_def(s: &ItemEnum) -> syn::Result<DerivedTS> {
             dependencies: Dependencies::new(crate_rename),
             export: enum_attr.export,
             export_to: enum_attr.export_to,
+            file_name: enum_attr.file_name,
+            global: enum_attr.global,
+            namespace: enum_attr.namespace,
+            literal_union: false,
+            decl_kind: DeclKind::TypeAlias,
             bound: enum_attr.bound,
+            helper_decls: Vec::new(),
         });
     }
 
+    if enum_attr.result_shape {
+        return result_shape(s, name, enum_attr);
+    }
+
+    if enum_attr.example_switch {
+        enum_attr.docs = with_example_switch(&enum_attr, s)?;
+    }
+
     let mut formatted_variants = Vec::new();
+    let mut declared_variants = Vec::new();
+    let mut helper_decls = Vec::new();
+    let mut event_map_entries = Vec::new();
+    let mut hierarchy_tags = Vec::new();
+    let mut hierarchy_members = Vec::new();
     let mut dependencies = Dependencies::new(crate_rename.clone());
     for variant in &s.variants {
-        format_variant(
+        declared_variants.extend(format_variant(
             &mut formatted_variants,
+            &mut helper_decls,
             &mut dependencies,
             &enum_attr,
+            &name,
             variant,
-        )?;
+            &s.generics,
+        )?);
+
+        if enum_attr.extractors {
+            helper_decls.extend(format_extractor(&enum_attr, &name, variant)?);
+        }
+
+        if enum_attr.event_map.is_some() {
+            event_map_entries.extend(format_event_map_entry(&enum_attr, variant, &s.generics)?);
+        }
+
+        if enum_attr.hierarchy {
+            if let Some((tag, member)) =
+                format_hierarchy_member(&enum_attr, &name, variant, &s.generics)?
+            {
+                hierarchy_tags.push(string_literal(&crate_rename, &tag));
+                hierarchy_members.push(member);
+            }
+        }
     }
 
+    let decl_kind = match enum_attr.native_enum {
+        Some(kind) => {
+            let members = native_enum_members(&enum_attr, kind, s)?;
+            // numeric enums are serialized as their discriminant, not as the name of the variant
+            if let NativeEnum::Numeric = kind {
+                formatted_variants = members.iter().map(|(_, value)| value.clone()).collect();
+            }
+            DeclKind::Enum(members)
+        }
+        None => DeclKind::TypeAlias,
+    };
+
+    if enum_attr.non_exhaustive {
+        let fallback = non_exhaustive_fallback(&enum_attr, s)?;
+        declared_variants.push(fallback.clone());
+        formatted_variants.push(fallback);
+    }
+
+    // only the declaration refers to the data types of the variants, since they are not declared
+    // where the enum is inlined
+    let decl_kind = match (decl_kind, enum_attr.variants_as_intersection) {
+        (DeclKind::TypeAlias, true) => {
+            DeclKind::TypeAliasOf(quote!([#(#declared_variants),*].join(" | ")))
+        }
+        (decl_kind, _) => decl_kind,
+    };
+
+    if let Some(event_map) = &enum_attr.event_map {
+        helper_decls.push(quote! {
+            format!("type {}{} = {{ {} }};", #event_map, generics, [#(#event_map_entries),*].join(" "))
+        });
+    }
+
+    if let (true, Tagged::Internally { tag }) = (enum_attr.hierarchy, enum_attr.tagged()?) {
+        let tag = raw_name_to_ts_field(&crate_rename, tag.to_owned());
+        helper_decls.push(quote! {
+            format!(
+                "interface {}Base {{ {}: {}, }}",
+                #name,
+                #tag,
+                [#(#hierarchy_tags),*].join(" | ")
+            )
+        });
+        helper_decls.extend(hierarchy_members);
+    }
+
+    let literal_union = is_literal_union(&enum_attr, s)?;
+
     Ok(DerivedTS {
         crate_rename,
         inline: quote!([#(#formatted_variants),*].join(" | ")),
@@ -67,17 +157,28 @@ pub(crate) fn r#enum_def(s: &ItemEnum) -> syn::Result<DerivedTS> {
         docs: enum_attr.docs,
         export: enum_attr.export,
         export_to: enum_attr.export_to,
+        file_name: enum_attr.file_name,
+        global: enum_attr.global,
+        namespace: enum_attr.namespace,
+        literal_union,
+        decl_kind,
         ts_name: name,
         bound: enum_attr.bound,
+        helper_decls,
     })
 }
 
+// push the formatted variant, returning how it appears in the declaration of the enum, unless it is
+// skipped
 fn format_variant(
     formatted_variants: &mut Vec<TokenStream>,
+    helper_decls: &mut Vec<TokenStream>,
     dependencies: &mut Dependencies,
     enum_attr: &EnumAttr,
+    enum_name: &str,
     variant: &Variant,
-) -> syn::Result<()> {
+    generics: &Generics,
+) -> syn::Result<Option<TokenStream>> {
     let crate_rename = enum_attr.crate_rename();
 
     // If `variant.fields` is not a `Fields::Named(_)` the `rename_all_fields`
@@ -88,30 +189,41 @@ fn format_variant(
     variant_attr.assert_validity(variant)?;
 
     if variant_attr.skip {
-        return Ok(());
+        return Ok(None);
     }
 
     let untagged_variant = variant_attr.untagged;
-    let name = match (variant_attr.rename.clone(), &enum_attr.rename_all) {
-        (Some(rn), _) => rn,
-        (None, None) => variant.ident.to_string(),
-        (None, Some(rn)) => rn.apply(&variant.ident.to_string()),
+    let name = variant_name(enum_attr, &variant_attr, variant);
+
+    // the synthetic discriminant of `#[ts(add_discriminant = "..")]` is added to every variant
+    // just like the tag of an internally tagged enum
+    let tagged = match (enum_attr.tagged()?, &enum_attr.add_discriminant) {
+        (Tagged::Untagged, Some(discriminant)) => Tagged::Internally { tag: discriminant },
+        (tagged, _) => tagged,
     };
 
     let struct_attr = StructAttr::from_variant(enum_attr, &variant_attr, &variant.fields);
+    if let (false, Tagged::Internally { tag }) = (untagged_variant, tagged) {
+        assert_no_tag_collision(tag, &struct_attr, variant)?;
+    }
+
     let variant_type = types::type_def(
         &struct_attr,
         // since we are generating the variant as a struct, it doesn't have a name
         &format_ident!("_"),
         &variant.fields,
+        generics,
     )?;
     let variant_dependencies = variant_type.dependencies;
     let inline_type = variant_type.inline;
+    let name = string_literal(&crate_rename, &name);
 
-    let formatted = match (untagged_variant, enum_attr.tagged()?) {
+    // the variant as it appears in the declaration of the enum, if it differs from `formatted`
+    let mut declared = None;
+    let formatted = match (untagged_variant, tagged) {
         (true, _) | (_, Tagged::Untagged) => quote!(#inline_type),
         (false, Tagged::Externally) => match &variant.fields {
-            Fields::Unit => quote!(format!("\"{}\"", #name)),
+            Fields::Unit => name,
             Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
                 let field = &unnamed.unnamed[0];
                 let field_attr = FieldAttr::from_attrs(&field.attrs)?;
@@ -119,56 +231,91 @@ fn format_variant(
                 field_attr.assert_validity(field)?;
 
                 if field_attr.skip {
-                    quote!(format!("\"{}\"", #name))
+                    name
                 } else {
-                    quote!(format!("{{ \"{}\": {} }}", #name, #inline_type))
+                    quote!(format!("{{ {}: {} }}", #name, #inline_type))
                 }
             }
-            _ => quote!(format!("{{ \"{}\": {} }}", #name, #inline_type)),
+            _ => quote!(format!("{{ {}: {} }}", #name, #inline_type)),
         },
-        (false, Tagged::Adjacently { tag, content }) => match &variant.fields {
-            Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
-                let field = &unnamed.unnamed[0];
-                let field_attr = FieldAttr::from_attrs(&unnamed.unnamed[0].attrs)?;
+        (false, Tagged::Adjacently { tag, content }) => {
+            let tag = string_literal(&crate_rename, tag);
+            let content = string_literal(&crate_rename, content);
+            match &variant.fields {
+                Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+                    let field = &unnamed.unnamed[0];
+                    let field_attr = FieldAttr::from_attrs(&unnamed.unnamed[0].attrs)?;
 
-                field_attr.assert_validity(field)?;
+                    field_attr.assert_validity(field)?;
 
-                if field_attr.skip {
-                    quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name))
-                } else {
-                    let ty = match field_attr.type_override {
-                        Some(type_override) => quote!(#type_override),
-                        None => {
-                            let ty = field_attr.type_as(&field.ty);
-                            quote!(<#ty as #crate_rename::TS>::name())
-                        }
-                    };
-                    quote!(format!("{{ \"{}\": \"{}\", \"{}\": {} }}", #tag, #name, #content, #ty))
+                    if field_attr.skip {
+                        quote!(format!("{{ {}: {} }}", #tag, #name))
+                    } else {
+                        let ty = match field_attr.type_override {
+                            Some(type_override) => {
+                                types::type_override_expr(&crate_rename, &type_override, generics)
+                            }
+                            None => {
+                                let ty = field_attr.type_as(&field.ty);
+                                quote!(<#ty as #crate_rename::TS>::name())
+                            }
+                        };
+                        quote!(format!("{{ {}: {}, {}: {} }}", #tag, #name, #content, #ty))
+                    }
                 }
+                Fields::Unit => quote!(format!("{{ {}: {} }}", #tag, #name)),
+                _ => quote!(format!("{{ {}: {}, {}: {} }}", #tag, #name, #content, #inline_type)),
             }
-            Fields::Unit => quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name)),
-            _ => quote!(
-                format!("{{ \"{}\": \"{}\", \"{}\": {} }}", #tag, #name, #content, #inline_type)
-            ),
-        },
-        (false, Tagged::Internally { tag }) => match variant_type.inline_flattened {
-            Some(inline_flattened) => quote! {
-                format!(
-                    "{{ \"{}\": \"{}\", {} }}",
-                    #tag,
-                    #name,
-                    // At this point inline_flattened looks like
-                    // { /* ...data */ }
-                    //
-                    // To be flattened, an internally tagged enum must not be
-                    // surrounded by braces, otherwise each variant will look like
-                    // { "tag": "name", { /* ...data */ } }
-                    // when we want it to look like
-                    // { "tag": "name", /* ...data */ }
-                    #inline_flattened.trim_matches(&['{', '}', ' '])
-                )
-            },
-            None => match &variant.fields {
+        }
+        (false, Tagged::Internally { tag })
+            if enum_attr.variants_as_intersection && matches!(variant.fields, Fields::Named(_)) =>
+        {
+            let tag = string_literal(&crate_rename, tag);
+
+            // the data of the variant is declared as a separate type `<Enum><Variant>Data`, which
+            // shares the generic parameters of the enum
+            let data_name = format!("{}{}Data", enum_name, to_ts_ident(&variant.ident));
+            helper_decls.push(quote! {
+                format!("type {}{} = {};", #data_name, generics, #inline_type)
+            });
+
+            // the generic arguments of the enum, e.g `<number>`
+            let generic_args = generics
+                .type_params()
+                .map(|ty| &ty.ident)
+                .map(|generic| quote!(<#generic as #crate_rename::TS>::name()))
+                .collect::<Vec<_>>();
+            let generic_args = match generic_args.is_empty() {
+                true => quote!(""),
+                false => quote!(format!("<{}>", [#(#generic_args),*].join(", "))),
+            };
+
+            declared = Some(quote! {
+                format!("{{ {}: {} }} & {}{}", #tag, #name, #data_name, #generic_args)
+            });
+
+            // where the enum is inlined, the data type is not declared, so the variant becomes a
+            // single object, just like without `variants_as_intersection`
+            let Fields::Named(fields) = &variant.fields else {
+                unreachable!("only struct variants are emitted as intersections")
+            };
+            named::tagged(
+                &struct_attr,
+                fields,
+                generics,
+                quote!(format!("{}: {},", #tag, #name)),
+            )?
+        }
+        (false, Tagged::Internally { tag }) => {
+            let tag = string_literal(&crate_rename, tag);
+            match &variant.fields {
+                // the tag becomes the first field of the variant
+                Fields::Named(fields) if !fields.named.is_empty() => named::tagged(
+                    &struct_attr,
+                    fields,
+                    generics,
+                    quote!(format!("{}: {},", #tag, #name)),
+                )?,
                 Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
                     let field = &unnamed.unnamed[0];
                     let field_attr = FieldAttr::from_attrs(&unnamed.unnamed[0].attrs)?;
@@ -176,32 +323,409 @@ fn format_variant(
                     field_attr.assert_validity(field)?;
 
                     if field_attr.skip {
-                        quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name))
+                        quote!(format!("{{ {}: {} }}", #tag, #name))
                     } else {
                         let ty = match field_attr.type_override {
-                            Some(type_override) => quote! { #type_override },
+                            Some(type_override) => {
+                                types::type_override_expr(&crate_rename, &type_override, generics)
+                            }
                             None => {
                                 let ty = field_attr.type_as(&field.ty);
                                 quote!(<#ty as #crate_rename::TS>::name())
                             }
                         };
 
-                        quote!(format!("{{ \"{}\": \"{}\" }} & {}", #tag, #name, #ty))
+                        quote!(format!("{{ {}: {} }} & {}", #tag, #name, #ty))
                     }
                 }
-                Fields::Unit => quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #name)),
-                _ => {
-                    quote!(format!("{{ \"{}\": \"{}\" }} & {}", #tag, #name, #inline_type))
-                }
-            },
-        },
+                Fields::Unit => quote!(format!("{{ {}: {} }}", #tag, #name)),
+                _ => quote!(format!("{{ {}: {} }} & {}", #tag, #name, #inline_type)),
+            }
+        }
     };
 
     dependencies.append(variant_dependencies);
+    let declared = declared.unwrap_or_else(|| formatted.clone());
     formatted_variants.push(formatted);
+    Ok(Some(declared))
+}
+
+// the tag of an internally tagged enum becomes a field of every struct variant, so none of their
+// fields may have the same name
+fn assert_no_tag_collision(
+    tag: &str,
+    struct_attr: &StructAttr,
+    variant: &Variant,
+) -> syn::Result<()> {
+    let Fields::Named(named) = &variant.fields else {
+        return Ok(());
+    };
+
+    for field in &named.named {
+        let field_attr = FieldAttr::from_attrs(&field.attrs)?;
+        if field_attr.skip || field_attr.flatten {
+            continue;
+        }
+
+        if types::named::field_name(field, &field_attr, struct_attr) == tag {
+            syn_err_spanned!(
+                field;
+                "the field `{}` collides with the tag of the enum",
+                tag
+            );
+        }
+    }
+
     Ok(())
 }
 
+// build an expression which expands to the `#[ts(extractors)]` alias of a single variant, e.g.
+// type Event_Click = Extract<Event, { "Click": unknown }>;
+fn format_extractor(
+    enum_attr: &EnumAttr,
+    enum_name: &str,
+    variant: &Variant,
+) -> syn::Result<Option<TokenStream>> {
+    let variant_attr = VariantAttr::from_attrs(&variant.attrs)?;
+
+    // untagged variants cannot be told apart from each other, so there's nothing to extract
+    if variant_attr.skip || variant_attr.untagged {
+        return Ok(None);
+    }
+
+    let crate_rename = enum_attr.crate_rename();
+    let name = string_literal(
+        &crate_rename,
+        &variant_name(enum_attr, &variant_attr, variant),
+    );
+    let shape = match enum_attr.tagged()? {
+        Tagged::Untagged => return Ok(None),
+        Tagged::Externally => match &variant.fields {
+            Fields::Unit => name,
+            Fields::Unnamed(unnamed)
+                if unnamed.unnamed.len() == 1
+                    && FieldAttr::from_attrs(&unnamed.unnamed[0].attrs)?.skip =>
+            {
+                name
+            }
+            _ => quote!(format!("{{ {}: unknown }}", #name)),
+        },
+        Tagged::Adjacently { tag, .. } | Tagged::Internally { tag } => {
+            let tag = string_literal(&crate_rename, tag);
+            quote!(format!("{{ {}: {} }}", #tag, #name))
+        }
+    };
+    let alias = format!("{}_{}", enum_name, to_ts_ident(&variant.ident));
+
+    Ok(Some(quote! {
+        format!("type {}{} = Extract<{}, {}>;", #alias, generics, name, #shape)
+    }))
+}
+
+// build an expression which expands to the entry of a single variant within the
+// `#[ts(event_map = "..")]` type, mapping the name of the variant to its data, e.g. `click: ClickData,`
+fn format_event_map_entry(
+    enum_attr: &EnumAttr,
+    variant: &Variant,
+    generics: &Generics,
+) -> syn::Result<Option<TokenStream>> {
+    let variant_attr = VariantAttr::from_attrs(&variant.attrs)?;
+
+    if variant_attr.skip {
+        return Ok(None);
+    }
+
+    let name = raw_name_to_ts_field(
+        &enum_attr.crate_rename(),
+        variant_name(enum_attr, &variant_attr, variant),
+    );
+    let struct_attr = StructAttr::from_variant(enum_attr, &variant_attr, &variant.fields);
+    let data =
+        types::type_def(&struct_attr, &format_ident!("_"), &variant.fields, generics)?.inline;
+
+    Ok(Some(quote!(format!("{}: {},", #name, #data))))
+}
+
+// build an expression which expands to the interface of a single variant within the
+// `#[ts(hierarchy)]` of an enum, e.g.
+// interface ShapeCircle extends ShapeBase { kind: "Circle", radius: number, }
+// Returns the name of the variant as well, which is one of the tags of the base interface.
+fn format_hierarchy_member(
+    enum_attr: &EnumAttr,
+    enum_name: &str,
+    variant: &Variant,
+    generics: &Generics,
+) -> syn::Result<Option<(String, TokenStream)>> {
+    let variant_attr = VariantAttr::from_attrs(&variant.attrs)?;
+
+    // untagged variants do not have a tag, so they can't extend the base interface
+    if variant_attr.skip || variant_attr.untagged {
+        return Ok(None);
+    }
+
+    let Tagged::Internally { tag } = enum_attr.tagged()? else {
+        return Ok(None);
+    };
+
+    let crate_rename = enum_attr.crate_rename();
+    let name = variant_name(enum_attr, &variant_attr, variant);
+    let interface = format!("{}{}", enum_name, to_ts_ident(&variant.ident));
+    let tag = raw_name_to_ts_field(&crate_rename, tag.to_owned());
+    let literal = string_literal(&crate_rename, &name);
+    let tag = quote!(format!("{}: {},", #tag, #literal));
+
+    let object = match &variant.fields {
+        Fields::Unit => quote!(format!("{{ {} }}", #tag)),
+        Fields::Named(named) if named.named.is_empty() => quote!(format!("{{ {} }}", #tag)),
+        Fields::Named(fields) => {
+            // an interface can't be intersected with the flattened fields
+            for field in &fields.named {
+                if FieldAttr::from_attrs(&field.attrs)?.flatten {
+                    syn_err_spanned!(field; "`flatten` cannot be used within a `hierarchy`");
+                }
+            }
+
+            let struct_attr = StructAttr::from_variant(enum_attr, &variant_attr, &variant.fields);
+            named::tagged(&struct_attr, fields, generics, tag)?
+        }
+        Fields::Unnamed(_) => syn_err_spanned!(
+            variant;
+            "`hierarchy` can only be used on enums with unit and struct variants"
+        ),
+    };
+
+    let member = quote! {
+        format!(
+            "interface {}{} extends {}Base {}",
+            #interface,
+            generics,
+            #enum_name,
+            #object
+        )
+    };
+
+    Ok(Some((name, member)))
+}
+
+// append an `@example` block to the docs of a `#[ts(example_switch)]` enum, showing how a value is
+// narrowed down to a single variant by switching over its tag
+fn with_example_switch(enum_attr: &EnumAttr, s: &ItemEnum) -> syn::Result<String> {
+    let (Tagged::Internally { tag } | Tagged::Adjacently { tag, .. }) = enum_attr.tagged()? else {
+        return Ok(enum_attr.docs.clone());
+    };
+
+    let tag = match is_ts_field(tag) {
+        true => format!("value.{}", tag),
+        false => format!("value[{}]", example_literal(tag)),
+    };
+
+    let mut lines = vec![" * @example".to_owned(), format!(" * switch ({}) {{", tag)];
+    for variant in &s.variants {
+        let variant_attr = VariantAttr::from_attrs(&variant.attrs)?;
+        if variant_attr.skip || variant_attr.untagged {
+            continue;
+        }
+
+        let name = variant_name(enum_attr, &variant_attr, variant);
+        lines.push(format!(" *   case {}:", example_literal(&name)));
+        lines.push(" *     break;".to_owned());
+    }
+    lines.push(" * }".to_owned());
+
+    Ok(match enum_attr.docs.strip_suffix(" */\n") {
+        Some(docs) => format!("{} *\n{}\n */\n", docs, lines.join("\n")),
+        None => format!("/**\n{}\n */\n", lines.join("\n")),
+    })
+}
+
+// a string literal within the `@example` block of `#[ts(example_switch)]`. The docs are generated at
+// compile time, so they always use double quotes. `*/` is escaped so it doesn't end the comment.
+fn example_literal(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '"' => out.push_str("\\\""),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '/' if out.ends_with('*') => out.push_str("\\/"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// the member added to the union of a `#[non_exhaustive]` enum, which matches any variant added in
+// the future. For unions of string literals, `string & {}` keeps the known literals suggested.
+fn non_exhaustive_fallback(enum_attr: &EnumAttr, s: &ItemEnum) -> syn::Result<TokenStream> {
+    if let Some(NativeEnum::Numeric) = enum_attr.native_enum {
+        return Ok(quote!("(number & {})".to_owned()));
+    }
+
+    Ok(match is_literal_union(enum_attr, s)? {
+        true => quote!("(string & {})".to_owned()),
+        false => quote!("{ [key: string]: unknown }".to_owned()),
+    })
+}
+
+// whether the enum is emitted as a union of string or number literals, which is the case for
+// externally tagged enums with only unit variants
+fn is_literal_union(enum_attr: &EnumAttr, s: &ItemEnum) -> syn::Result<bool> {
+    if !matches!(enum_attr.tagged()?, Tagged::Externally) {
+        return Ok(false);
+    }
+
+    for variant in &s.variants {
+        let variant_attr = VariantAttr::from_attrs(&variant.attrs)?;
+        if variant_attr.skip {
+            continue;
+        }
+        if !matches!(variant.fields, Fields::Unit) {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// members of the TypeScript enum generated for `#[ts(enum)]`, as pairs of the member name and an
+// expression producing its value as a TypeScript literal. Deprecated variants get a `@deprecated` tag before their name.
+fn native_enum_members(
+    enum_attr: &EnumAttr,
+    kind: NativeEnum,
+    s: &ItemEnum,
+) -> syn::Result<Vec<(String, TokenStream)>> {
+    let mut members = Vec::new();
+    // `None` if the previous discriminant was `i128::MAX`, so the next one cannot be implicit
+    let mut next_discriminant = Some(0);
+    for variant in &s.variants {
+        let discriminant = match (&variant.discriminant, next_discriminant) {
+            (Some((_, expr)), _) => parse_discriminant(expr)?,
+            (None, Some(next)) => next,
+            (None, None) => syn_err_spanned!(variant; "discriminant overflowed"),
+        };
+        next_discriminant = discriminant.checked_add(1);
+
+        let variant_attr = VariantAttr::from_attrs(&variant.attrs)?;
+        if variant_attr.skip {
+            continue;
+        }
+
+        let value = match kind {
+            NativeEnum::String => string_literal(
+                &enum_attr.crate_rename(),
+                &variant_name(enum_attr, &variant_attr, variant),
+            ),
+            NativeEnum::Numeric => {
+                let discriminant = discriminant.to_string();
+                quote!(#discriminant.to_owned())
+            }
+        };
+        let member = match parse_deprecated(&variant.attrs)? {
+            Some(deprecated) => format!("/** {} */ {}", deprecated, to_ts_ident(&variant.ident)),
+            None => to_ts_ident(&variant.ident),
+        };
+        members.push((member, value));
+    }
+    Ok(members)
+}
+
+// the value of an explicit discriminant like `A = 1` or `B = -1`
+fn parse_discriminant(expr: &Expr) -> syn::Result<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse(),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => parse_discriminant(expr).map(|value| -value),
+        _ => syn_err_spanned!(
+            expr;
+            "only integer literals are supported as discriminants of a TypeScript enum"
+        ),
+    }
+}
+
+// the name of a variant in TypeScript, taking `rename` and `rename_all` into account
+fn variant_name(enum_attr: &EnumAttr, variant_attr: &VariantAttr, variant: &Variant) -> String {
+    match (variant_attr.rename.clone(), &enum_attr.rename_all) {
+        (Some(rn), _) => rn,
+        (None, None) => variant.ident.to_string(),
+        (None, Some(rn)) => rn.apply(&variant.ident.to_string()),
+    }
+}
+
+// bindings for `#[ts(result_shape)]`, which turns an enum with a success and an error variant into
+// { success: true, data: T } | { success: false, error: E }
+fn result_shape(s: &ItemEnum, name: String, enum_attr: EnumAttr) -> syn::Result<DerivedTS> {
+    let crate_rename = enum_attr.crate_rename();
+
+    // skipped variants are never serialized, so they are not part of the shape
+    let mut variants = Vec::new();
+    for variant in &s.variants {
+        let variant_attr = VariantAttr::from_attrs(&variant.attrs)?;
+        variant_attr.assert_validity(variant)?;
+
+        if !variant_attr.skip {
+            variants.push((variant, variant_attr));
+        }
+    }
+
+    let is_newtype = |v: &Variant| matches!(&v.fields, Fields::Unnamed(f) if f.unnamed.len() == 1);
+    if variants.len() != 2 || !variants.iter().all(|(variant, _)| is_newtype(variant)) {
+        syn_err_spanned!(
+            s;
+            "`result_shape` can only be used on enums with exactly two newtype variants, e.g. `Ok(T)` and `Err(E)`"
+        );
+    }
+
+    let mut dependencies = Dependencies::new(crate_rename.clone());
+    let mut inline_types = Vec::new();
+    for (variant, variant_attr) in variants {
+        let struct_attr = StructAttr::from_variant(&enum_attr, &variant_attr, &variant.fields);
+        let variant_type = types::type_def(
+            &struct_attr,
+            &format_ident!("_"),
+            &variant.fields,
+            &s.generics,
+        )?;
+
+        dependencies.append(variant_type.dependencies);
+        inline_types.push(variant_type.inline);
+    }
+
+    let (data, error) = (&inline_types[0], &inline_types[1]);
+    let formatted = quote! {
+        format!(
+            "{{ success: true, data: {} }} | {{ success: false, error: {} }}",
+            #data,
+            #error
+        )
+    };
+
+    Ok(DerivedTS {
+        crate_rename,
+        inline: formatted.clone(),
+        inline_flattened: Some(quote!(format!("({})", #formatted))),
+        dependencies,
+        docs: enum_attr.docs,
+        export: enum_attr.export,
+        export_to: enum_attr.export_to,
+        file_name: enum_attr.file_name,
+        global: enum_attr.global,
+        namespace: enum_attr.namespace,
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
+        ts_name: name,
+        bound: enum_attr.bound,
+        helper_decls: Vec::new(),
+    })
+}
+
 // bindings for an empty enum (`never` in TS)
 fn empty_enum(name: impl Into<String>, enum_attr: EnumAttr) -> DerivedTS {
     let name = name.into();
@@ -214,7 +738,13 @@ fn empty_enum(name: impl Into<String>, enum_attr: EnumAttr) -> DerivedTS {
         dependencies: Dependencies::new(crate_rename),
         export: enum_attr.export,
         export_to: enum_attr.export_to,
+        file_name: enum_attr.file_name,
+        global: enum_attr.global,
+        namespace: enum_attr.namespace,
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name,
         bound: enum_attr.bound,
+        helper_decls: Vec::new(),
     }
 }
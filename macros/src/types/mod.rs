@@ -1,8 +1,10 @@
-use syn::{Fields, Ident, ItemStruct, Result};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Expr, ExprLit, Field, Fields, Generics, Ident, ItemStruct, Lit, Path, Result, Type};
 
 use crate::{
     attr::{Attr, StructAttr},
-    utils::to_ts_ident,
+    deps::Dependencies,
     DerivedTS,
 };
 
@@ -19,30 +21,155 @@ pub(crate) use r#enum::r#enum_def;
 pub(crate) fn struct_def(s: &ItemStruct) -> Result<DerivedTS> {
     let attr = StructAttr::from_attrs(&s.attrs)?;
 
-    type_def(&attr, &s.ident, &s.fields)
+    #[cfg(feature = "serde-compat")]
+    if attr.strict {
+        crate::attr::assert_strict_struct(s)?;
+    }
+
+    type_def(&attr, &s.ident, &s.fields, &s.generics)
 }
 
-fn type_def(attr: &StructAttr, ident: &Ident, fields: &Fields) -> Result<DerivedTS> {
+fn type_def(
+    attr: &StructAttr,
+    ident: &Ident,
+    fields: &Fields,
+    generics: &Generics,
+) -> Result<DerivedTS> {
     attr.assert_validity(fields)?;
 
-    let name = attr.rename.clone().unwrap_or_else(|| to_ts_ident(ident));
+    let name = attr.ts_name(ident);
     if let Some(attr_type_override) = &attr.type_override {
         return type_override::type_override_struct(attr, &name, attr_type_override);
     }
     if let Some(attr_type_as) = &attr.type_as {
         return type_as::type_as_struct(attr, &name, attr_type_as);
     }
+    if attr.transparent {
+        return newtype::newtype(attr, &name, fields.iter().next().unwrap(), generics);
+    }
 
-    match fields {
+    let mut derived = match fields {
         Fields::Named(named) => match named.named.len() {
             0 => unit::empty_object(attr, &name),
-            _ => named::named(attr, &name, named),
+            _ => named::named(attr, &name, named, generics),
         },
         Fields::Unnamed(unnamed) => match unnamed.unnamed.len() {
             0 => unit::empty_array(attr, &name),
-            1 => newtype::newtype(attr, &name, unnamed),
-            _ => tuple::tuple(attr, &name, unnamed),
+            1 if attr.tuple_labels.is_none() => {
+                newtype::newtype(attr, &name, &unnamed.unnamed[0], generics)
+            }
+            _ => tuple::tuple(attr, &name, unnamed, generics),
         },
         Fields::Unit => unit::null(attr, &name),
+    }?;
+
+    if attr.readonly_deep {
+        let inline = derived.inline;
+        derived.inline = quote!(format!("Readonly<{}>", #inline));
+        // keep the properties readonly when the type is flattened into another one
+        derived.inline_flattened = derived
+            .inline_flattened
+            .map(|flattened| quote!(format!("Readonly<{}>", #flattened)));
+    }
+
+    Ok(derived)
+}
+
+// build an expression which expands to the type given with `#[ts(type = "..")]` on a field.
+// `{T}`, where `T` is a generic parameter of the type, is replaced with the name of `T`, while all
+// other braces, e.g. of a mapped type like `{ [K in keyof {T}]: string }`, are left as they are.
+fn type_override_expr(
+    crate_rename: &Path,
+    type_override: &str,
+    generics: &Generics,
+) -> TokenStream {
+    let params = generics.type_params().map(|p| &p.ident).collect::<Vec<_>>();
+
+    let mut format_str = String::new();
+    let mut args = Vec::new();
+    let mut rest = type_override;
+    while let Some(start) = rest.find('{') {
+        format_str.push_str(&rest[..start].replace('}', "}}"));
+        let after = &rest[start + 1..];
+
+        let param = params.iter().find(|param| {
+            let param = param.to_string();
+            after.starts_with(&param) && after[param.len()..].starts_with('}')
+        });
+        match param {
+            Some(param) => {
+                format_str.push_str("{}");
+                args.push(quote!(<#param as #crate_rename::TS>::name()));
+                rest = &after[param.to_string().len() + 1..];
+            }
+            None => {
+                format_str.push_str("{{");
+                rest = after;
+            }
+        }
+    }
+    format_str.push_str(&rest.replace('}', "}}"));
+
+    match args.is_empty() {
+        true => quote!(#type_override.to_owned()),
+        false => quote!(format!(#format_str, #(#args),*)),
+    }
+}
+
+// build an expression which expands to a labeled tuple like `[x: number, y: number]`, given
+// `#[ts(tuple_labels = "..")]` on a field of type `ty`, which must be a tuple or an array of a
+// fixed length.
+fn labeled_tuple_expr(
+    crate_rename: &Path,
+    labels: &[String],
+    field: &Field,
+    ty: &Type,
+    inline: bool,
+    dependencies: &mut Dependencies,
+) -> Result<TokenStream> {
+    let Some(elems) = tuple_elements(ty) else {
+        syn_err_spanned!(
+            field;
+            "`tuple_labels` can only be used on fields which are tuples or arrays of a fixed length"
+        );
+    };
+
+    if labels.len() != elems.len() {
+        syn_err_spanned!(
+            field;
+            "`tuple_labels` expects {} labels, one for each element, but {} were given",
+            elems.len(),
+            labels.len()
+        );
+    }
+
+    let elems = elems.into_iter().zip(labels).map(|(elem, label)| {
+        let formatted = if inline {
+            dependencies.append_from(elem);
+            quote!(<#elem as #crate_rename::TS>::inline())
+        } else {
+            dependencies.push(elem);
+            quote!(<#elem as #crate_rename::TS>::name())
+        };
+        quote!(format!("{}: {}", #label, #formatted))
+    });
+
+    Ok(quote!(format!("[{}]", [#(#elems),*].join(", "))))
+}
+
+// Returns the types of the elements of `ty` if it is a tuple `(A, B)` or an array `[T; N]` with a
+// literal length
+fn tuple_elements(ty: &Type) -> Option<Vec<&Type>> {
+    match ty {
+        Type::Tuple(tuple) => Some(tuple.elems.iter().collect()),
+        Type::Array(array) => match &array.len {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(len), ..
+            }) => Some(vec![&*array.elem; len.base10_parse().ok()?]),
+            _ => None,
+        },
+        Type::Group(group) => tuple_elements(&group.elem),
+        Type::Paren(paren) => tuple_elements(&paren.elem),
+        _ => None,
     }
 }
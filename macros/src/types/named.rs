@@ -1,58 +1,67 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
-    spanned::Spanned, Field, FieldsNamed, GenericArgument, Path, PathArguments, Result, Type,
+    spanned::Spanned, Field, FieldsNamed, GenericArgument, Generics, PathArguments, Result, Type,
 };
 
 use crate::{
-    attr::{Attr, ContainerAttr, FieldAttr, Inflection, Optional, StructAttr},
+    attr::{slice_element, Attr, ContainerAttr, FieldAttr, Optional, StructAttr},
     deps::Dependencies,
-    utils::{raw_name_to_ts_field, to_ts_ident},
-    DerivedTS,
+    utils::{jsdoc_markdown, raw_name_to_ts_field, string_literal, to_ts_ident},
+    DeclKind, DerivedTS,
 };
 
-pub(crate) fn named(attr: &StructAttr, name: &str, fields: &FieldsNamed) -> Result<DerivedTS> {
+pub(crate) fn named(
+    attr: &StructAttr,
+    name: &str,
+    fields: &FieldsNamed,
+    generics: &Generics,
+) -> Result<DerivedTS> {
     let crate_rename = attr.crate_rename();
 
     let mut formatted_fields = Vec::new();
     let mut flattened_fields = Vec::new();
+    let mut field_names = Vec::new();
     let mut dependencies = Dependencies::new(crate_rename.clone());
 
     if let Some(tag) = &attr.tag {
-        let formatted = format!("{}: \"{}\",", tag, name);
-        formatted_fields.push(quote! {
-            #formatted.to_string()
-        });
+        let readonly = if attr.readonly { "readonly " } else { "" };
+        let name = string_literal(&crate_rename, name);
+        field_names.push(string_literal(&crate_rename, tag));
+        formatted_fields.push(quote!(format!("{}{}: {},", #readonly, #tag, #name)));
     }
 
     for field in &fields.named {
         format_field(
-            &crate_rename,
             &mut formatted_fields,
             &mut flattened_fields,
+            &mut field_names,
             &mut dependencies,
             field,
-            &attr.rename_all,
+            attr,
+            generics,
         )?;
     }
 
-    let fields = quote!(<[String]>::join(&[#(#formatted_fields),*], " "));
-    let flattened = quote!(<[String]>::join(&[#(#flattened_fields),*], " & "));
-
     let inline = match (formatted_fields.len(), flattened_fields.len()) {
-        (0, 0) => quote!("{  }".to_owned()),
-        (_, 0) => quote!(format!("{{ {} }}", #fields)),
-        (0, 1) => quote!(#flattened.trim_matches(|c| c == '(' || c == ')').to_owned()),
-        (0, _) => quote!(#flattened),
-        (_, _) => quote!(format!("{{ {} }} & {}", #fields, #flattened)),
+        (0, 1) => {
+            let flattened = &flattened_fields[0];
+            quote!(#flattened.trim_matches(|c| c == '(' || c == ')').to_owned())
+        }
+        _ => object(&formatted_fields, &flattened_fields),
     };
 
-    let inline_flattened = match (formatted_fields.len(), flattened_fields.len()) {
-        (0, 0) => quote!("{  }".to_owned()),
-        (_, 0) => quote!(format!("{{ {} }}", #fields)),
-        (0, _) => quote!(#flattened),
-        (_, _) => quote!(format!("{{ {} }} & {}", #fields, #flattened)),
-    };
+    let inline_flattened = object(&formatted_fields, &flattened_fields);
+
+    let mut helper_decls = Vec::new();
+    if attr.field_names_union {
+        // all fields might be skipped or flattened
+        let union = match field_names.is_empty() {
+            true => quote!("never"),
+            false => quote!([#(#field_names),*].join(" | ")),
+        };
+        helper_decls.push(quote!(format!("type {}Fields = {};", #name, #union)));
+    }
 
     Ok(DerivedTS {
         crate_rename,
@@ -64,11 +73,77 @@ pub(crate) fn named(attr: &StructAttr, name: &str, fields: &FieldsNamed) -> Resu
         dependencies,
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: match attr.interface {
+            true => DeclKind::Interface,
+            false => DeclKind::TypeAlias,
+        },
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls,
     })
 }
 
+// build an expression which expands to the object of a struct variant, starting with the tag of
+// its enum, e.g. `{ "kind": "Circle", radius: number, }`.
+// `tag` is an expression which expands to the formatted tag field, e.g. `"kind": "Circle",`
+pub(crate) fn tagged(
+    attr: &StructAttr,
+    fields: &FieldsNamed,
+    generics: &Generics,
+    tag: TokenStream,
+) -> Result<TokenStream> {
+    let mut formatted_fields = vec![tag];
+    let mut flattened_fields = Vec::new();
+    let mut dependencies = Dependencies::new(attr.crate_rename());
+
+    for field in &fields.named {
+        format_field(
+            &mut formatted_fields,
+            &mut flattened_fields,
+            &mut Vec::new(),
+            &mut dependencies,
+            field,
+            attr,
+            generics,
+        )?;
+    }
+
+    let object = object(&formatted_fields, &flattened_fields);
+    Ok(quote!(#object.replace(" } & { ", " ")))
+}
+
+// build an expression which expands to an object containing the formatted fields, intersected with
+// the flattened fields, e.g. `{ a: number, } & ({ b: string, } | { c: string, })`
+fn object(formatted_fields: &[TokenStream], flattened_fields: &[TokenStream]) -> TokenStream {
+    let fields = quote!(<[String]>::join(&[#(#formatted_fields),*], " "));
+    let flattened = quote!(<[String]>::join(&[#(#flattened_fields),*], " & "));
+
+    match (formatted_fields.len(), flattened_fields.len()) {
+        (0, 0) => quote!("{  }".to_owned()),
+        (_, 0) => quote!(format!("{{ {} }}", #fields)),
+        (0, _) => quote!(#flattened),
+        (_, _) => quote!(format!("{{ {} }} & {}", #fields, #flattened)),
+    }
+}
+
+// the name of a named field in TypeScript, taking `rename` and `rename_all` into account
+pub(crate) fn field_name(
+    field: &Field,
+    field_attr: &FieldAttr,
+    struct_attr: &StructAttr,
+) -> String {
+    let field_name = to_ts_ident(field.ident.as_ref().unwrap());
+    match (&field_attr.rename, &struct_attr.rename_all) {
+        (Some(rn), _) => rn.clone(),
+        (None, Some(rn)) => rn.apply(&field_name),
+        (None, None) => field_name,
+    }
+}
+
 // build an expression which expands to a string, representing a single field of a struct.
 //
 // formatted_fields will contain all the fields that do not contain the flatten
@@ -79,14 +154,18 @@ pub(crate) fn named(attr: &StructAttr, name: &str, fields: &FieldsNamed) -> Resu
 // in their respective formats, which for a named struct is the same as formatted_fields,
 // but for enums is
 // ({ /* variant data */ } | { /* variant data */ })
+//
+// field_names will contain the quoted names of all fields that are not flattened
 fn format_field(
-    crate_rename: &Path,
     formatted_fields: &mut Vec<TokenStream>,
     flattened_fields: &mut Vec<TokenStream>,
+    field_names: &mut Vec<TokenStream>,
     dependencies: &mut Dependencies,
     field: &Field,
-    rename_all: &Option<Inflection>,
+    struct_attr: &StructAttr,
+    generics: &Generics,
 ) -> Result<()> {
+    let crate_rename = struct_attr.crate_rename();
     let field_attr = FieldAttr::from_attrs(&field.attrs)?;
 
     field_attr.assert_validity(field)?;
@@ -97,10 +176,18 @@ fn format_field(
 
     let parsed_ty = field_attr.type_as(&field.ty);
 
-    let (ty, optional_annotation) = match field_attr.optional {
+    // `#[ts(optional)]` on the struct applies to every `Option<T>` field, unless the field is
+    // marked with `#[ts(optional)]` itself
+    let optional = match extract_option_argument(&parsed_ty) {
+        Ok(_) if !field_attr.flatten => field_attr.optional.or(struct_attr.optional),
+        _ => field_attr.optional,
+    };
+
+    let (ty, optional_annotation) = match optional {
         Optional {
             optional: true,
             nullable,
+            ..
         } => {
             let inner_type = extract_option_argument(&parsed_ty)?; // inner type of the optional
             match nullable {
@@ -113,41 +200,72 @@ fn format_field(
         } => (&parsed_ty, ""),
     };
 
+    // with `#[ts(readonly)]` on the field or the struct, the field is prefixed with `readonly`
+    let readonly = field_attr.readonly || struct_attr.readonly;
+
     if field_attr.flatten {
-        flattened_fields.push(quote!(<#ty as #crate_rename::TS>::inline_flattened()));
+        // a flattened field has no key which could be marked as `readonly`
+        flattened_fields.push(match readonly {
+            true => quote!(format!("Readonly<{}>", <#ty as #crate_rename::TS>::inline_flattened())),
+            false => quote!(<#ty as #crate_rename::TS>::inline_flattened()),
+        });
         dependencies.append_from(ty);
         return Ok(());
     }
 
-    let formatted_ty = field_attr
-        .type_override
-        .map(|t| quote!(#t))
-        .unwrap_or_else(|| {
-            if field_attr.inline {
-                dependencies.append_from(ty);
-                quote!(<#ty as #crate_rename::TS>::inline())
-            } else {
-                dependencies.push(ty);
-                quote!(<#ty as #crate_rename::TS>::name())
-            }
-        });
+    let formatted_ty = match (&field_attr.type_override, &field_attr.tuple_labels) {
+        (Some(t), _) => super::type_override_expr(&crate_rename, t, generics),
+        (None, Some(labels)) => super::labeled_tuple_expr(
+            &crate_rename,
+            labels,
+            field,
+            ty,
+            field_attr.inline,
+            dependencies,
+        )?,
+        (None, None) if field_attr.inline => {
+            dependencies.append_from(ty);
+            quote!(<#ty as #crate_rename::TS>::inline())
+        }
+        (None, None) => {
+            dependencies.push(ty);
+            quote!(<#ty as #crate_rename::TS>::name())
+        }
+    };
 
-    let field_name = to_ts_ident(field.ident.as_ref().unwrap());
-    let name = match (field_attr.rename, rename_all) {
-        (Some(rn), _) => rn,
-        (None, Some(rn)) => rn.apply(&field_name),
-        (None, None) => field_name,
+    // slices are also emitted as `ReadonlyArray<T>` instead of `Array<T>` with `#[ts(readonly)]`
+    let readonly_slice =
+        slice_element(ty).filter(|_| readonly && field_attr.type_override.is_none());
+    let formatted_ty = match readonly_slice {
+        Some(elem) if field_attr.inline => {
+            quote!(format!("ReadonlyArray<{}>", <#elem as #crate_rename::TS>::inline()))
+        }
+        Some(elem) => quote!(format!("ReadonlyArray<{}>", <#elem as #crate_rename::TS>::name())),
+        None => formatted_ty,
+    };
+
+    let formatted_ty = match optional.undefined {
+        true => quote!(format!("{} | undefined", #formatted_ty)),
+        false => formatted_ty,
     };
-    let valid_name = raw_name_to_ts_field(name);
+
+    let name = field_name(field, &field_attr, struct_attr);
+    field_names.push(string_literal(&crate_rename, &name));
+    let readonly = if readonly { "readonly " } else { "" };
+    let valid_name = raw_name_to_ts_field(&crate_rename, name);
 
     // Start every doc string with a newline, because when other characters are in front, it is not "understood" by VSCode
-    let docs = match field_attr.docs.is_empty() {
-        true => "".to_string(),
-        false => format!("\n{}", &field_attr.docs),
+    let docs = match (
+        struct_attr.no_docs || field_attr.docs.is_empty(),
+        struct_attr.jsdoc_markdown,
+    ) {
+        (true, _) => "".to_string(),
+        (false, true) => format!("\n{}", jsdoc_markdown(&field_attr.docs)),
+        (false, false) => format!("\n{}", &field_attr.docs),
     };
 
     formatted_fields.push(quote! {
-        format!("{}{}{}: {},", #docs, #valid_name, #optional_annotation, #formatted_ty)
+        format!("{}{}{}{}: {},", #docs, #readonly, #valid_name, #optional_annotation, #formatted_ty)
     });
 
     Ok(())
@@ -1,15 +1,18 @@
 use quote::quote;
-use syn::{FieldsUnnamed, Result};
+use syn::{Field, Generics, Result};
 
 use crate::{
     attr::{Attr, ContainerAttr, FieldAttr, StructAttr},
     deps::Dependencies,
-    DerivedTS,
+    DeclKind, DerivedTS,
 };
 
-pub(crate) fn newtype(attr: &StructAttr, name: &str, fields: &FieldsUnnamed) -> Result<DerivedTS> {
-    let inner = fields.unnamed.first().unwrap();
-
+pub(crate) fn newtype(
+    attr: &StructAttr,
+    name: &str,
+    inner: &Field,
+    generics: &Generics,
+) -> Result<DerivedTS> {
     let field_attr = FieldAttr::from_attrs(&inner.attrs)?;
     field_attr.assert_validity(inner)?;
 
@@ -23,16 +26,24 @@ pub(crate) fn newtype(attr: &StructAttr, name: &str, fields: &FieldsUnnamed) ->
 
     let mut dependencies = Dependencies::new(crate_rename.clone());
 
-    match (&field_attr.type_override, field_attr.inline) {
-        (Some(_), _) => (),
-        (None, true) => dependencies.append_from(&inner_ty),
-        (None, false) => dependencies.push(&inner_ty),
-    };
-
-    let inline_def = match field_attr.type_override {
-        Some(ref o) => quote!(#o.to_owned()),
-        None if field_attr.inline => quote!(<#inner_ty as #crate_rename::TS>::inline()),
-        None => quote!(<#inner_ty as #crate_rename::TS>::name()),
+    let inline_def = match (&field_attr.type_override, &field_attr.tuple_labels) {
+        (Some(o), _) => super::type_override_expr(&crate_rename, o, generics),
+        (None, Some(labels)) => super::labeled_tuple_expr(
+            &crate_rename,
+            labels,
+            inner,
+            &inner_ty,
+            field_attr.inline,
+            &mut dependencies,
+        )?,
+        (None, None) if field_attr.inline => {
+            dependencies.append_from(&inner_ty);
+            quote!(<#inner_ty as #crate_rename::TS>::inline())
+        }
+        (None, None) => {
+            dependencies.push(&inner_ty);
+            quote!(<#inner_ty as #crate_rename::TS>::name())
+        }
     };
 
     Ok(DerivedTS {
@@ -43,7 +54,13 @@ pub(crate) fn newtype(attr: &StructAttr, name: &str, fields: &FieldsUnnamed) ->
         dependencies,
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
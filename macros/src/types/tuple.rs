@@ -1,14 +1,19 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Field, FieldsUnnamed, Path, Result};
+use syn::{Field, FieldsUnnamed, Generics, Path, Result};
 
 use crate::{
     attr::{Attr, ContainerAttr, FieldAttr, StructAttr},
     deps::Dependencies,
-    DerivedTS,
+    DeclKind, DerivedTS,
 };
 
-pub(crate) fn tuple(attr: &StructAttr, name: &str, fields: &FieldsUnnamed) -> Result<DerivedTS> {
+pub(crate) fn tuple(
+    attr: &StructAttr,
+    name: &str,
+    fields: &FieldsUnnamed,
+    generics: &Generics,
+) -> Result<DerivedTS> {
     let crate_rename = attr.crate_rename();
     let mut formatted_fields = Vec::new();
     let mut dependencies = Dependencies::new(crate_rename.clone());
@@ -18,9 +23,19 @@ pub(crate) fn tuple(attr: &StructAttr, name: &str, fields: &FieldsUnnamed) -> Re
             &mut formatted_fields,
             &mut dependencies,
             field,
+            generics,
         )?;
     }
 
+    // with `#[ts(tuple_labels = "..")]`, every element is prefixed with its label
+    if let Some(labels) = &attr.tuple_labels {
+        formatted_fields = formatted_fields
+            .into_iter()
+            .zip(labels)
+            .map(|(field, label)| quote!(format!("{}: {}", #label, #field)))
+            .collect();
+    }
+
     Ok(DerivedTS {
         crate_rename,
         inline: quote! {
@@ -34,8 +49,14 @@ pub(crate) fn tuple(attr: &StructAttr, name: &str, fields: &FieldsUnnamed) -> Re
         dependencies,
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
 
@@ -44,6 +65,7 @@ fn format_field(
     formatted_fields: &mut Vec<TokenStream>,
     dependencies: &mut Dependencies,
     field: &Field,
+    generics: &Generics,
 ) -> Result<()> {
     let field_attr = FieldAttr::from_attrs(&field.attrs)?;
     field_attr.assert_validity(field)?;
@@ -54,17 +76,27 @@ fn format_field(
 
     let ty = field_attr.type_as(&field.ty);
 
-    formatted_fields.push(match field_attr.type_override {
-        Some(ref o) => quote!(#o.to_owned()),
-        None if field_attr.inline => quote!(<#ty as #crate_rename::TS>::inline()),
-        None => quote!(<#ty as #crate_rename::TS>::name()),
-    });
-
-    match (field_attr.inline, field_attr.type_override) {
-        (_, Some(_)) => (),
-        (false, _) => dependencies.push(&ty),
-        (true, _) => dependencies.append_from(&ty),
-    };
+    formatted_fields.push(
+        match (&field_attr.type_override, &field_attr.tuple_labels) {
+            (Some(o), _) => super::type_override_expr(crate_rename, o, generics),
+            (None, Some(labels)) => super::labeled_tuple_expr(
+                crate_rename,
+                labels,
+                field,
+                &ty,
+                field_attr.inline,
+                dependencies,
+            )?,
+            (None, None) if field_attr.inline => {
+                dependencies.append_from(&ty);
+                quote!(<#ty as #crate_rename::TS>::inline())
+            }
+            (None, None) => {
+                dependencies.push(&ty);
+                quote!(<#ty as #crate_rename::TS>::name())
+            }
+        },
+    );
 
     Ok(())
 }
@@ -4,7 +4,7 @@ use syn::{Result, Type};
 use crate::{
     attr::{ContainerAttr, EnumAttr, StructAttr},
     deps::Dependencies,
-    DerivedTS,
+    DeclKind, DerivedTS,
 };
 
 pub(crate) fn type_as_struct(attr: &StructAttr, name: &str, type_as: &Type) -> Result<DerivedTS> {
@@ -18,8 +18,14 @@ pub(crate) fn type_as_struct(attr: &StructAttr, name: &str, type_as: &Type) -> R
         dependencies: Dependencies::new(crate_rename),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
 
@@ -34,7 +40,13 @@ pub(crate) fn type_as_enum(attr: &EnumAttr, name: &str, type_as: &Type) -> Resul
         dependencies: Dependencies::new(crate_rename),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
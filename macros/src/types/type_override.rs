@@ -4,7 +4,7 @@ use syn::Result;
 use crate::{
     attr::{ContainerAttr, EnumAttr, StructAttr},
     deps::Dependencies,
-    DerivedTS,
+    DeclKind, DerivedTS,
 };
 
 pub(crate) fn type_override_struct(
@@ -22,8 +22,14 @@ pub(crate) fn type_override_struct(
         dependencies: Dependencies::new(crate_rename),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
 
@@ -42,7 +48,13 @@ pub(crate) fn type_override_enum(
         dependencies: Dependencies::new(crate_rename),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
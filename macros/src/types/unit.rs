@@ -4,7 +4,7 @@ use syn::Result;
 use crate::{
     attr::{ContainerAttr, StructAttr},
     deps::Dependencies,
-    DerivedTS,
+    DeclKind, DerivedTS,
 };
 
 pub(crate) fn empty_object(attr: &StructAttr, name: &str) -> Result<DerivedTS> {
@@ -19,8 +19,14 @@ pub(crate) fn empty_object(attr: &StructAttr, name: &str) -> Result<DerivedTS> {
         dependencies: Dependencies::new(crate_rename),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
 
@@ -36,8 +42,14 @@ pub(crate) fn empty_array(attr: &StructAttr, name: &str) -> Result<DerivedTS> {
         dependencies: Dependencies::new(crate_rename),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
 
@@ -53,8 +65,14 @@ pub(crate) fn null(attr: &StructAttr, name: &str) -> Result<DerivedTS> {
         dependencies: Dependencies::new(crate_rename),
         export: attr.export,
         export_to: attr.export_to.clone(),
+        file_name: attr.file_name.clone(),
+        global: attr.global,
+        namespace: attr.namespace.clone(),
+        literal_union: false,
+        decl_kind: DeclKind::TypeAlias,
         ts_name: name.to_owned(),
         bound: attr.bound.clone(),
+        helper_decls: Vec::new(),
     })
 }
 
@@ -67,5 +85,9 @@ fn check_attributes(attr: &StructAttr) -> Result<()> {
         syn_err!("`tag` is not applicable to unit structs");
     }
 
+    if attr.field_names_union {
+        syn_err!("`field_names_union` is not applicable to unit structs");
+    }
+
     Ok(())
 }
@@ -2,8 +2,8 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::{
-    spanned::Spanned, Attribute, Error, Expr, ExprLit, GenericParam, Generics, Lit, Meta, Path,
-    Result, Token, TypeParamBound,
+    spanned::Spanned, Attribute, Error, Expr, ExprLit, GenericParam, Generics, Lit, Meta,
+    MetaNameValue, Path, Result, Token, TraitBoundModifier, TypeParamBound,
 };
 
 use super::attr::Attr;
@@ -74,26 +74,32 @@ pub fn to_ts_ident(ident: &Ident) -> String {
     }
 }
 
-/// Convert an arbitrary name to a valid Typescript field name.
-///
-/// If the name contains special characters or if its first character
-/// is a number it will be wrapped in quotes.
-pub fn raw_name_to_ts_field(value: String) -> String {
+/// Build an expression producing `value` as a Typescript string literal, using the quote style
+/// configured at runtime.
+pub fn string_literal(crate_rename: &Path, value: &str) -> TokenStream {
+    quote!(#crate_rename::string_literal(#value))
+}
+
+/// Whether an arbitrary name can be used as a Typescript field name without quoting it, which is
+/// not the case if it contains special characters or if its first character is a number.
+pub fn is_ts_field(value: &str) -> bool {
     let valid_chars = value
         .chars()
         .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
 
-    let does_not_start_with_digit = value
-        .chars()
-        .next()
-        .map_or(true, |first| !first.is_numeric());
+    let does_not_start_with_digit = !value.chars().next().is_some_and(char::is_numeric);
 
-    let valid = valid_chars && does_not_start_with_digit;
+    valid_chars && does_not_start_with_digit
+}
 
-    if valid {
-        value
-    } else {
-        format!(r#""{value}""#)
+/// Convert an arbitrary name to an expression producing a valid Typescript field name.
+///
+/// If the name contains special characters or if its first character
+/// is a number it will be wrapped in quotes.
+pub fn raw_name_to_ts_field(crate_rename: &Path, value: String) -> TokenStream {
+    match is_ts_field(&value) {
+        true => quote!(#value),
+        false => string_literal(crate_rename, &value),
     }
 }
 
@@ -142,8 +148,9 @@ where
 }
 
 /// Return doc comments parsed and formatted as JSDoc.
+/// If the item is `#[deprecated]`, a `@deprecated` tag is appended.
 pub fn parse_docs(attrs: &[Attribute]) -> Result<String> {
-    let lines = attrs
+    let mut lines = attrs
         .iter()
         .filter_map(|a| match a.meta {
             Meta::NameValue(ref x) if x.path.is_ident("doc") => Some(x),
@@ -164,12 +171,214 @@ pub fn parse_docs(attrs: &[Attribute]) -> Result<String> {
         })
         .collect::<Result<Vec<_>>>()?;
 
+    if let Some(deprecated) = parse_deprecated(attrs)? {
+        if !lines.is_empty() {
+            lines.push(" *".to_owned());
+        }
+        lines.push(format!(" * {}", deprecated));
+    }
+
     Ok(match lines.is_empty() {
         true => "".to_owned(),
         false => format!("/**\n{}\n */\n", lines.join("\n")),
     })
 }
 
+/// Append a tag like `@default 0` to JSDoc produced by [`parse_docs`]. The tag is separated from the
+/// description by an empty line, unless it follows other tags.
+pub fn append_jsdoc_tag(docs: &str, tag: &str) -> String {
+    match docs.strip_suffix(" */\n") {
+        None => format!("/**\n * {}\n */\n", tag),
+        Some(docs) if docs.lines().last().is_some_and(|l| l.starts_with(" * @")) => {
+            format!("{} * {}\n */\n", docs, tag)
+        }
+        Some(docs) => format!("{} *\n * {}\n */\n", docs, tag),
+    }
+}
+
+/// Return a JSDoc `@deprecated` tag, containing the note of `#[deprecated(note = "..")]` or
+/// `#[deprecated = ".."]` if there is one, if the item is `#[deprecated]`.
+pub fn parse_deprecated(attrs: &[Attribute]) -> Result<Option<String>> {
+    let Some(attr) = attrs.iter().find(|a| a.path().is_ident("deprecated")) else {
+        return Ok(None);
+    };
+
+    let note = match &attr.meta {
+        Meta::Path(_) => None,
+        Meta::NameValue(MetaNameValue {
+            value:
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(note),
+                    ..
+                }),
+            ..
+        }) => Some(note.value()),
+        Meta::NameValue(_) => syn_err!(attr.span(); "expected a string literal as the note"),
+        Meta::List(_) => {
+            let mut note = None;
+            attr.parse_nested_meta(|meta| {
+                let value = meta.value()?.parse::<syn::LitStr>()?;
+                if meta.path.is_ident("note") {
+                    note = Some(value.value());
+                }
+                Ok(())
+            })?;
+            note
+        }
+    };
+
+    Ok(Some(match note {
+        Some(note) => format!("@deprecated {}", note.trim()),
+        None => "@deprecated".to_owned(),
+    }))
+}
+
+/// Convert the markdown in JSDoc produced by [`parse_docs`] into JSDoc syntax.
+/// Fenced code blocks become `@example` blocks and links become `{@link}` tags.
+pub fn jsdoc_markdown(docs: &str) -> String {
+    // `Some(is_rust)` while inside of a fenced code block
+    let mut fence: Option<bool> = None;
+    let mut lines = Vec::new();
+    // an `@example` tag extends up to the next tag, so code blocks are moved behind the prose
+    let mut examples = Vec::new();
+
+    for line in docs.lines() {
+        let content = match line {
+            "/**" => {
+                lines.push(line.to_owned());
+                continue;
+            }
+            " */" => {
+                lines.append(&mut examples);
+                lines.push(line.to_owned());
+                continue;
+            }
+            line => line.strip_prefix(" *").unwrap_or(line),
+        };
+
+        let repeated_gap = content.trim().is_empty() && lines.last().is_some_and(|l| l == " *");
+        match (content.trim().strip_prefix("```"), fence) {
+            (Some(_), Some(_)) => fence = None,
+            (Some(lang), None) => {
+                fence = Some(is_rust_code_block(lang));
+                examples.push(" * @example".to_owned());
+            }
+            // rustdoc hides lines starting with `# ` in rust code blocks
+            (None, Some(true)) if content.trim() == "#" || content.trim().starts_with("# ") => (),
+            (None, Some(_)) => examples.push(line.to_owned()),
+            // the paragraphs around a code block are separated by a single empty line
+            (None, None) if repeated_gap => (),
+            (None, None) => lines.push(format!(" *{}", jsdoc_links(content))),
+        }
+    }
+    lines.append(&mut examples);
+
+    match lines.is_empty() {
+        true => "".to_owned(),
+        false => format!("{}\n", lines.join("\n")),
+    }
+}
+
+fn is_rust_code_block(lang: &str) -> bool {
+    lang.split(',').map(str::trim).all(|attr| {
+        matches!(
+            attr,
+            "" | "rust" | "ignore" | "no_run" | "should_panic" | "compile_fail"
+        ) || attr.starts_with("edition")
+    })
+}
+
+// Replace `` [`Foo`] ``, `[crate::Foo]`, `[text](target)` and `[text][target]` with `{@link}` tags,
+// leaving inline code and plain brackets like `values[i]` as they are.
+fn jsdoc_links(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+
+    while let Some(start) = rest.find(['[', '`']) {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+
+        if rest[start..].starts_with('`') {
+            let end = after.find('`').map(|end| end + 1).unwrap_or(after.len());
+            out.push('`');
+            out.push_str(&after[..end]);
+            rest = &after[end..];
+            continue;
+        }
+
+        let Some(end) = after.find(']') else {
+            out.push('[');
+            rest = after;
+            continue;
+        };
+        let (text, tail) = (&after[..end], &after[end + 1..]);
+
+        let inline = tail
+            .strip_prefix('(')
+            .and_then(|t| Some(&t[..t.find(')')?]));
+        let reference = tail
+            .strip_prefix('[')
+            .and_then(|t| Some(&t[..t.find(']')?]));
+
+        match inline.or(reference) {
+            // `[text][]` links to `text`
+            Some("") if reference.is_some() => {
+                out.push_str(&jsdoc_link(text, None));
+                rest = &tail[2..];
+            }
+            Some(target) => {
+                out.push_str(&jsdoc_link(target, Some(text)));
+                rest = &tail[target.len() + 2..];
+            }
+            None if is_intra_doc_link(text) => {
+                out.push_str(&jsdoc_link(text, None));
+                rest = tail;
+            }
+            None => {
+                out.push('[');
+                rest = after;
+            }
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+// whether `[text]` is an intra-doc link, which requires `text` to be backticked or a path
+fn is_intra_doc_link(text: &str) -> bool {
+    let path = text.trim_matches('`');
+    let backticked = text.len() > path.len() && text.starts_with('`') && text.ends_with('`');
+    (backticked || path.contains("::"))
+        && path.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && path
+            .chars()
+            .all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '@' | '!' | '(' | ')'))
+}
+
+fn jsdoc_link(target: &str, text: Option<&str>) -> String {
+    let target = match target.contains('/') || target.contains('.') {
+        // urls and relative links are kept as they are
+        true => target.to_owned(),
+        // rust paths are reduced to the name of the item
+        false => {
+            let path = target
+                .trim_matches('`')
+                .trim_end_matches("()")
+                .trim_end_matches('!');
+            let path = path.rsplit('@').next().unwrap_or(path);
+            path.rsplit("::").next().unwrap_or(path).to_owned()
+        }
+    };
+
+    match text {
+        Some(text) if text.trim_matches('`') != target => {
+            format!("{{@link {} | {}}}", target, text)
+        }
+        _ => format!("{{@link {}}}", target),
+    }
+}
+
 #[cfg(feature = "serde-compat")]
 mod warning {
     use std::{fmt::Display, io::Write};
@@ -277,10 +486,12 @@ pub fn get_traits_from_bounds(bounds: &Punctuated<TypeParamBound, Token![+]>) ->
     bounds
         .iter()
         .filter_map(|b| match b {
+            // `?Sized` is not a trait which could be derived
+            TypeParamBound::Trait(t) if matches!(t.modifier, TraitBoundModifier::Maybe(_)) => None,
             TypeParamBound::Trait(t) => Some(t),
             _ => None,
         })
-        .map(|b| {
+        .flat_map(|b| {
             b.path
                 .segments
                 .iter()
@@ -288,6 +499,5 @@ pub fn get_traits_from_bounds(bounds: &Punctuated<TypeParamBound, Token![+]>) ->
                 .filter(|i| !ignored_traits.iter().any(|it| i == it))
                 .collect::<Vec<_>>()
         })
-        .flatten()
         .collect::<Vec<_>>()
 }
@@ -0,0 +1,85 @@
+#[cfg(feature = "branded-numbers")]
+use super::{
+    AtomicI16, AtomicI32, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU8, AtomicUsize,
+    NonZeroI16, NonZeroI32, NonZeroI8, NonZeroIsize, NonZeroU16, NonZeroU32, NonZeroU8,
+    NonZeroUsize,
+};
+use super::{Path, TS};
+use crate::export::prelude;
+
+#[cfg(feature = "branded-numbers")]
+const NUMBER_BRANDS: [&str; 2] = ["Integer", "Float"];
+
+#[cfg(feature = "branded-char")]
+const CHAR_BRAND: &str = "Char";
+
+/// The declarations of the brand types, which are part of the prelude
+#[allow(clippy::vec_init_then_push)]
+pub(crate) fn declarations() -> Vec<String> {
+    #[allow(unused_mut)]
+    let mut brands = Vec::new();
+
+    #[cfg(feature = "branded-char")]
+    brands.push(CHAR_BRAND);
+
+    #[cfg(feature = "branded-numbers")]
+    brands.extend(NUMBER_BRANDS);
+
+    brands.into_iter().map(declare).collect()
+}
+
+/// Replaces a brand type with the type it brands, for places where TypeScript does not accept an
+/// intersection type, like the key of an index signature
+pub(crate) fn unbrand(ts: String) -> String {
+    #[cfg(feature = "branded-numbers")]
+    if NUMBER_BRANDS.contains(&ts.as_str()) {
+        return "number".to_owned();
+    }
+
+    #[cfg(feature = "branded-char")]
+    if ts == CHAR_BRAND {
+        return "string".to_owned();
+    }
+
+    ts
+}
+
+fn declare(brand: &str) -> String {
+    match brand {
+        "Char" => format!(
+            "/**\n * @remarks single character\n */\ntype {brand} = string & {{ readonly __brand: {} }};",
+            crate::string_literal(brand)
+        ),
+        _ => format!(
+            "type {brand} = number & {{ readonly __brand: {} }};",
+            crate::string_literal(brand)
+        ),
+    }
+}
+
+// generate impls for types which are emitted as a brand type declared in the prelude
+macro_rules! impl_branded {
+    ($($($ty:ty),* => $brand:literal),*) => { $($(
+        impl TS for $ty {
+            fn name() -> String { $brand.to_owned() }
+            fn decl() -> String { declare($brand) }
+            fn decl_concrete() -> String { declare($brand) }
+            fn inline() -> String { Self::name() }
+            fn inline_flattened() -> String { panic!("{} cannot be flattened", Self::name()) }
+            fn output_path() -> Option<&'static Path> { Some(Path::new(prelude::PATH)) }
+            fn is_primitive() -> bool { true }
+        }
+    )*)* };
+}
+
+#[cfg(feature = "branded-numbers")]
+impl_branded! {
+    u8, i8, NonZeroU8, NonZeroI8, AtomicU8, AtomicI8,
+    u16, i16, NonZeroU16, NonZeroI16, AtomicU16, AtomicI16,
+    u32, i32, NonZeroU32, NonZeroI32, AtomicU32, AtomicI32,
+    usize, isize, NonZeroUsize, NonZeroIsize, AtomicUsize, AtomicIsize => "Integer",
+    f32, f64 => "Float"
+}
+
+#[cfg(feature = "branded-char")]
+impl_branded!(char => "Char");
@@ -20,7 +20,14 @@ macro_rules! impl_dummy {
     )*};
 }
 
-impl_primitives!(NaiveDateTime, NaiveDate, NaiveTime, Month, Weekday, Duration => "string");
+impl_primitives!(NaiveDateTime, NaiveDate, NaiveTime, Month, Weekday => "string");
+
+// `Duration` has no serde representation of its own, so whether it is emitted as an ISO-8601
+// string or a number of seconds / milliseconds is up to the `chrono-duration-number` feature
+#[cfg(not(feature = "chrono-duration-number"))]
+impl_primitives!(Duration => "string");
+#[cfg(feature = "chrono-duration-number")]
+impl_primitives!(Duration => "number");
 impl_dummy!(Utc, Local, FixedOffset);
 
 impl<T: TimeZone + 'static> TS for DateTime<T> {
@@ -0,0 +1,69 @@
+//! A bundle is a single file containing the declarations of a type and all of its dependencies.
+//! Since every declaration lives in the same file, no imports are generated.
+//!
+//! Dependencies are declared before the types using them, so TypeScript never has to resolve a
+//! forward reference, except for recursive types.
+
+use std::{any::TypeId, collections::HashSet};
+
+use super::{generate_decl, prelude, NOTE};
+use crate::{
+    error::{Error, Result},
+    typelist::{TypeList, TypeVisitor},
+    TS,
+};
+
+/// Returns the bundle of `T` and all of its dependencies
+pub(crate) fn generate<T: TS + ?Sized + 'static>() -> Result<String> {
+    if T::output_path().is_none() {
+        return Err(Error::CannotBeExported(std::any::type_name::<T>()));
+    }
+
+    let mut bundle = Bundle::default();
+    bundle.push::<T>();
+
+    let mut buffer = NOTE.to_owned();
+    buffer.push_str(&bundle.decls.join("\n\n"));
+    Ok(buffer)
+}
+
+#[derive(Default)]
+struct Bundle {
+    seen: HashSet<TypeId>,
+    decls: Vec<String>,
+    prelude: bool,
+}
+
+impl Bundle {
+    // pushes the declarations of all dependencies of `T`, followed by the declaration of `T`
+    fn push<T: TS + ?Sized + 'static>(&mut self) {
+        if !self.seen.insert(TypeId::of::<T>()) {
+            return;
+        }
+
+        // all helper types share the prelude, which is only declared once
+        if prelude::is_prelude(T::output_path()) {
+            if !std::mem::replace(&mut self.prelude, true) {
+                let mut decl = String::new();
+                prelude::generate(&mut decl);
+                self.decls.push(decl);
+            }
+            return;
+        }
+
+        T::dependency_types().for_each(self);
+
+        let mut decl = String::new();
+        generate_decl::<T>(&mut decl);
+        self.decls.push(decl);
+    }
+}
+
+impl TypeVisitor for Bundle {
+    fn visit<T: TS + 'static + ?Sized>(&mut self) {
+        // types which cannot be exported (e.g. primitives) have no declaration
+        if T::output_path().is_some() {
+            self.push::<T>();
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! With the `TS_GEN_INDEX` environment variable set to `true`, exporting a type together with its
+//! dependencies additionally writes an `index.ts` file into the export directory, which re-exports
+//! every exported module.
+
+use std::{collections::BTreeSet, io::ErrorKind, path::Path};
+
+use super::{import_path, line_ending::LineEnding, NOTE};
+use crate::error::Result;
+
+/// The file, relative to the export directory, in which all exported modules are re-exported
+pub(crate) const PATH: &str = "index.ts";
+
+/// Reads `TS_GEN_INDEX`, which enables generating the index if it is `true`.
+pub(crate) fn enabled() -> bool {
+    matches!(std::env::var("TS_GEN_INDEX").as_deref(), Ok("true"))
+}
+
+/// Adds a re-export of every file in `exported` to the index within `out_dir`.
+/// Since types are usually exported by separate tests, the re-exports already present in the index
+/// are kept, so the index lists every module exported into `out_dir` exactly once, sorted by path.
+/// Re-exports of files which no longer exist, e.g. because a type was renamed, are dropped.
+pub(crate) fn generate(out_dir: &Path, exported: &[&Path]) -> Result<()> {
+    let index = out_dir.join(PATH);
+
+    let mut modules = match std::fs::read_to_string(&index) {
+        Ok(content) => content
+            .lines()
+            .filter_map(parse_reexport)
+            .filter(|module| module_exists(out_dir, module))
+            .collect(),
+        Err(e) if e.kind() == ErrorKind::NotFound => BTreeSet::new(),
+        Err(e) => return Err(e.into()),
+    };
+    modules.extend(
+        exported
+            .iter()
+            .map(|path| import_path(&index, &out_dir.join(path))),
+    );
+
+    let mut buffer = NOTE.to_owned();
+    for module in modules {
+        buffer.push_str(&format!(
+            "export * from {};\n",
+            crate::string_literal(&module)
+        ));
+    }
+
+    let buffer = LineEnding::from_env().apply(buffer);
+
+    std::fs::create_dir_all(out_dir)?;
+    std::fs::write(index, buffer)?;
+    Ok(())
+}
+
+/// Returns the module of a line like `export * from "./User";`, regardless of its quotes
+fn parse_reexport(line: &str) -> Option<String> {
+    let module = line
+        .trim()
+        .strip_prefix("export * from ")?
+        .strip_suffix(';')?;
+    Some(module.get(1..module.len().checked_sub(1)?)?.to_owned())
+}
+
+/// Returns whether the file a module of the index refers to still exists within `out_dir`
+fn module_exists(out_dir: &Path, module: &str) -> bool {
+    let module = match cfg!(feature = "import-esm") {
+        true => module.strip_suffix(".js").unwrap_or(module),
+        false => module,
+    };
+    out_dir.join(format!("{}.ts", module)).is_file()
+}
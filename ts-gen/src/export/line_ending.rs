@@ -0,0 +1,26 @@
+/// Line endings used in the generated files, configured using the `TS_GEN_LINE_ENDING`
+/// environment variable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum LineEnding {
+    Lf,
+    Crlf,
+}
+
+impl LineEnding {
+    /// Reads `TS_GEN_LINE_ENDING`, which may be either `lf` (the default) or `crlf`.
+    pub(crate) fn from_env() -> Self {
+        match std::env::var("TS_GEN_LINE_ENDING").as_deref() {
+            Ok("crlf") => Self::Crlf,
+            _ => Self::Lf,
+        }
+    }
+
+    /// Replaces all line endings within `ts` with this line ending.
+    pub(crate) fn apply(self, ts: String) -> String {
+        match self {
+            Self::Lf if !ts.contains("\r\n") => ts,
+            Self::Lf => ts.replace("\r\n", "\n"),
+            Self::Crlf => ts.replace("\r\n", "\n").replace('\n', "\r\n"),
+        }
+    }
+}
@@ -2,7 +2,7 @@ pub use std::any::TypeId;
 
 use std::{
     borrow::Cow,
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     fmt::Write,
     fs::File,
     path::{Component, Path, PathBuf},
@@ -10,18 +10,28 @@ use std::{
 };
 
 use crate::error::{Error, Result};
-use crate::TS;
+use crate::{Dependency, TS};
 
+mod bundle;
+mod index;
+mod line_ending;
 mod path;
+pub(crate) mod prelude;
+pub(crate) mod quote;
 
 pub(crate) use recursive_export::export_all_into;
 
+/// Lock to make sure only one file will be written at a time.
+/// In the future, it might make sense to replace this with something more clever to only prevent
+/// two threads from writing the **same** file concurrently.
+static FILE_LOCK: Mutex<()> = Mutex::new(());
+
 const NOTE: &str = "// This file was generated by [ts-gen](https://github.com/VlaydDetect/ts-gen). Do not edit this file manually.\n";
 
 mod recursive_export {
     use std::{any::TypeId, collections::HashSet, path::Path};
 
-    use super::export_into;
+    use super::{index, render_into, write_all, Output, FILE_LOCK};
     use crate::error::{Error, Result};
     use crate::{
         typelist::{TypeList, TypeVisitor},
@@ -31,15 +41,31 @@ mod recursive_export {
     /// Exports `T` to the file specified by the `#[ts(export_to = ..)]` attribute within the given
     /// base directory.
     /// Additionally, all dependencies of `T` will be exported as well.
+    /// If enabled using `TS_GEN_INDEX`, the exported files are re-exported from the index.
+    ///
+    /// All files are rendered before any of them is written, so writing them can be parallelized
+    /// with the `parallel-export` feature.
     pub(crate) fn export_all_into<T: TS + ?Sized + 'static>(
         out_dir: impl AsRef<Path>,
     ) -> Result<()> {
         let mut seen = HashSet::new();
-        export_recursive::<T>(&mut seen, out_dir)
+        let mut outputs = Vec::new();
+        let mut exported = Vec::new();
+        render_recursive::<T>(&mut seen, &mut outputs, &mut exported, &out_dir)?;
+
+        write_all(&outputs)?;
+
+        if index::enabled() {
+            let _lock = FILE_LOCK.lock().unwrap();
+            index::generate(out_dir.as_ref(), &exported)?;
+        }
+        Ok(())
     }
 
     struct Visit<'a> {
         seen: &'a mut HashSet<TypeId>,
+        outputs: &'a mut Vec<Output>,
+        exported: &'a mut Vec<&'static Path>,
         out_dir: &'a Path,
         error: Option<Error>,
     }
@@ -52,13 +78,16 @@ mod recursive_export {
                 return;
             }
 
-            self.error = export_recursive::<T>(self.seen, self.out_dir).err();
+            self.error =
+                render_recursive::<T>(self.seen, self.outputs, self.exported, self.out_dir).err();
         }
     }
 
-    // exports T, then recursively calls itself with all of its dependencies
-    fn export_recursive<T: TS + ?Sized + 'static>(
+    // renders T, then recursively calls itself with all of its dependencies
+    fn render_recursive<T: TS + ?Sized + 'static>(
         seen: &mut HashSet<TypeId>,
+        outputs: &mut Vec<Output>,
+        exported: &mut Vec<&'static Path>,
         out_dir: impl AsRef<Path>,
     ) -> Result<()> {
         if !seen.insert(TypeId::of::<T>()) {
@@ -66,10 +95,13 @@ mod recursive_export {
         }
         let out_dir = out_dir.as_ref();
 
-        export_into::<T>(out_dir)?;
+        outputs.push(render_into::<T>(out_dir)?);
+        exported.extend(T::output_path());
 
         let mut visitor = Visit {
             seen,
+            outputs,
+            exported,
             out_dir,
             error: None,
         };
@@ -83,84 +115,187 @@ mod recursive_export {
     }
 }
 
-/// Export `T` to the file specified by the `#[ts(export_to = ..)]` attribute
-pub(crate) fn export_into<T: TS + ?Sized + 'static>(out_dir: impl AsRef<Path>) -> Result<()> {
+/// A generated file, which is rendered but not yet written
+pub(crate) struct Output {
+    path: PathBuf,
+    buffer: String,
+    /// The line describing the exported type in `ts_gen.meta`, if the `generate-metadata` feature
+    /// is enabled
+    metadata: Option<String>,
+}
+
+impl Output {
+    fn write(&self) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        // Manually write to file & call `sync_data`. Otherwise, calling `fs::read(path)`
+        // immediately after `T::export()` might result in an empty file.
+        use std::io::Write;
+        let mut file = File::create(&self.path)?;
+        file.write_all(self.buffer.as_bytes())?;
+        file.sync_data()?;
+        Ok(())
+    }
+}
+
+/// Render `T` for the file specified by the `#[ts(export_to = ..)]` attribute
+fn render_into<T: TS + ?Sized + 'static>(out_dir: impl AsRef<Path>) -> Result<Output> {
     let path = T::output_path()
         .ok_or_else(std::any::type_name::<T>)
         .map_err(Error::CannotBeExported)?;
     let path = out_dir.as_ref().join(path);
 
-    export_to::<T, _>(path::absolute(path)?)
+    render::<T>(path::absolute(path)?)
 }
 
-/// Export `T` to the file specified by the `path` argument.
-pub(crate) fn export_to<T: TS + ?Sized + 'static, P: AsRef<Path>>(path: P) -> Result<()> {
-    // Lock to make sure only one file will be written at a time.
-    // In the future, it might make sense to replace this with something more clever to only prevent
-    // two threads from writing the **same** file concurrently.
-    static FILE_LOCK: Mutex<()> = Mutex::new(());
+/// Render `T` for the file specified by the `path` argument.
+fn render<T: TS + ?Sized + 'static>(path: PathBuf) -> Result<Output> {
+    let buffer = format_output(&path, export_to_string::<T>()?)?;
 
-    #[allow(unused_mut)]
-    let mut buffer = export_to_string::<T>()?;
+    let metadata = match cfg!(feature = "generate-metadata") {
+        true => {
+            let relative_path = T::output_path()
+                .ok_or_else(std::any::type_name::<T>)
+                .map_err(Error::CannotBeExported)?
+                .to_string_lossy();
 
-    // format output
-    #[cfg(feature = "format")]
-    {
-        use dprint_plugin_typescript::{configuration::ConfigurationBuilder, format_text};
+            let type_ts_name = T::ident();
+            let type_rs_name = std::any::type_name::<T>().split('<').next().unwrap();
+            let generics = generic_count(&T::name());
+            let global = T::GLOBAL;
 
-        let fmt_cfg = ConfigurationBuilder::new().deno().build();
-        if let Some(formatted) = format_text(path.as_ref(), &buffer, &fmt_cfg)
-            .map_err(|e| Error::Formatting(e.to_string()))?
-        {
-            buffer = formatted;
+            Some(format!(
+                "{type_ts_name},{type_rs_name},{generics},{global},./{relative_path}\n"
+            ))
         }
+        false => None,
+    };
+
+    Ok(Output {
+        path,
+        buffer,
+        metadata,
+    })
+}
+
+/// The number of generic parameters in `name`, e.g. `2` for `Pair<A, B>`.
+/// Exported types are always named using the dummy generic parameters, so the arguments are plain
+/// identifiers.
+fn generic_count(name: &str) -> usize {
+    match name.find('<') {
+        None => 0,
+        Some(i) => name[i..].split(',').count(),
     }
+}
+
+/// Export `T` to the file specified by the `path` argument.
+pub(crate) fn export_to<T: TS + ?Sized + 'static, P: AsRef<Path>>(path: P) -> Result<()> {
+    write_all(&[render::<T>(path.as_ref().to_owned())?])
+}
+
+/// Write all `outputs`, followed by their metadata.
+/// No other file is written until all of them are written, even with the `parallel-export`
+/// feature, where multiple files are written at the same time.
+fn write_all(outputs: &[Output]) -> Result<()> {
+    let _lock = FILE_LOCK.lock().unwrap();
+    write_files(outputs)?;
+
+    let metadata = outputs
+        .iter()
+        .filter_map(|output| output.metadata.as_deref())
+        .collect::<String>();
+    if !metadata.is_empty() {
+        use std::io::Write;
+        std::fs::OpenOptions::new()
+            .append(true)
+            .create(true)
+            .open(default_out_dir().join("ts_gen.meta"))?
+            .write_all(metadata.as_bytes())?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "parallel-export"))]
+fn write_files(outputs: &[Output]) -> Result<()> {
+    outputs.iter().try_for_each(Output::write)
+}
+
+/// Write all `outputs` in parallel, distributing them evenly across all available threads
+#[cfg(feature = "parallel-export")]
+fn write_files(outputs: &[Output]) -> Result<()> {
+    let threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = outputs.len().div_ceil(threads).max(1);
+
+    std::thread::scope(|scope| {
+        let handles = outputs
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(|| chunk.iter().try_for_each(Output::write)))
+            .collect::<Vec<_>>();
+
+        handles
+            .into_iter()
+            .try_for_each(|handle| handle.join().expect("failed to write bindings"))
+    })
+}
+
+/// Export `T` together with all of its dependencies into the single file specified by `path`.
+pub(crate) fn export_bundle_to<T: TS + ?Sized + 'static, P: AsRef<Path>>(path: P) -> Result<()> {
+    let buffer = format_output(path.as_ref(), bundle::generate::<T>()?)?;
 
     if let Some(parent) = path.as_ref().parent() {
         std::fs::create_dir_all(parent)?;
     }
 
-    let lock = FILE_LOCK.lock().unwrap();
-    {
-        // Manually write to file & call `sync_data`. Otherwise, calling `fs::read(path)`
-        // immediately after `T::export()` might result in an empty file.
-        use std::io::Write;
-        let mut file = File::create(&path)?;
-        file.write_all(buffer.as_bytes())?;
-        file.sync_data()?;
-
-        if cfg!(feature = "generate-metadata") {
-            let relative_path = T::output_path()
-                .ok_or_else(std::any::type_name::<T>)
-                .map_err(Error::CannotBeExported)?
-                .to_string_lossy();
+    let _lock = FILE_LOCK.lock().unwrap();
+    std::fs::write(path, buffer)?;
+    Ok(())
+}
 
-            let type_ts_name = T::ident();
-            let type_rs_name = std::any::type_name::<T>().split('<').next().unwrap();
+/// Formats the output which is about to be written to `path`, if the `format` feature is enabled,
+/// and applies the configured line endings.
+#[allow(unused_variables, unused_mut)]
+fn format_output(path: &Path, mut buffer: String) -> Result<String> {
+    #[cfg(feature = "format")]
+    {
+        use dprint_plugin_typescript::{configuration::ConfigurationBuilder, format_text};
 
-            std::fs::OpenOptions::new()
-                .append(true)
-                .create(true)
-                .open(default_out_dir().join("ts_gen.meta"))?
-                .write_fmt(format_args!(
-                    "{type_ts_name},{type_rs_name},./{relative_path}\n"
-                ))?;
+        let fmt_cfg = ConfigurationBuilder::new().deno().build();
+        if let Some(formatted) =
+            format_text(path, &buffer, &fmt_cfg).map_err(|e| Error::Formatting(e.to_string()))?
+        {
+            buffer = formatted;
         }
     }
 
-    drop(lock);
-    Ok(())
+    Ok(line_ending::LineEnding::from_env().apply(buffer))
 }
 
 /// Returns the generated definition for `T`.
 pub(crate) fn export_to_string<T: TS + ?Sized + 'static>() -> Result<String> {
     let mut buffer = String::with_capacity(1024);
     buffer.push_str(NOTE);
+
+    // all helper types share the prelude, so exporting any of them writes the whole prelude
+    if prelude::is_prelude(T::output_path()) {
+        prelude::generate(&mut buffer);
+        return Ok(buffer);
+    }
+
     generate_imports::<T>(&mut buffer, default_out_dir())?;
     generate_decl::<T>(&mut buffer);
     Ok(buffer)
 }
 
+/// Returns the generated definition for `T`, formatted like the exported file.
+pub(crate) fn export_to_string_formatted<T: TS + ?Sized + 'static>() -> Result<String> {
+    let path = T::output_path()
+        .ok_or_else(std::any::type_name::<T>)
+        .map_err(Error::CannotBeExported)?;
+    format_output(path, export_to_string::<T>()?)
+}
+
 pub(crate) fn default_out_dir() -> Cow<'static, Path> {
     match std::env::var("TS_GEN_EXPORT_DIR") {
         Err(..) => Cow::Borrowed(Path::new("./bindings")),
@@ -170,6 +305,36 @@ pub(crate) fn default_out_dir() -> Cow<'static, Path> {
 
 /// Push the declaration of `T`
 fn generate_decl<T: TS + ?Sized>(out: &mut String) {
+    if T::GLOBAL {
+        let mut decl = String::new();
+        generate_exported_decl::<T>(&mut decl, "");
+        out.push_str("declare global {\n");
+        push_indented(out, &decl);
+        out.push_str("}\n\nexport {};");
+    } else if let Some(namespace) = T::NAMESPACE {
+        let mut decl = String::new();
+        generate_exported_decl::<T>(&mut decl, "export ");
+        writeln!(out, "export namespace {} {{", namespace).unwrap();
+        push_indented(out, &decl);
+        out.push('}');
+    } else {
+        generate_exported_decl::<T>(out, "export ");
+    }
+}
+
+/// Push every line of `decl`, indented by one level
+fn push_indented(out: &mut String, decl: &str) {
+    for line in decl.lines() {
+        match line {
+            "" => out.push('\n'),
+            line => writeln!(out, "    {}", line).unwrap(),
+        }
+    }
+}
+
+/// Push the docs and declaration of `T`, together with its helper declarations, each of them
+/// starting with `prefix`.
+fn generate_exported_decl<T: TS + ?Sized>(out: &mut String, prefix: &str) {
     // Type Docs
     let docs = &T::DOCS;
     if let Some(docs) = docs {
@@ -177,48 +342,183 @@ fn generate_decl<T: TS + ?Sized>(out: &mut String) {
     }
 
     // Type Definition
-    out.push_str("export ");
+    out.push_str(prefix);
     out.push_str(&T::decl());
+
+    for helper in T::helper_decls() {
+        out.push_str("\n\n");
+        out.push_str(prefix);
+        out.push_str(&helper);
+    }
 }
 
-/// Push an import statement for all dependencies of `T`.
+/// Returns the import statements for all dependencies of `T`.
+pub(crate) fn imports<T: TS + ?Sized + 'static>() -> Result<Vec<String>> {
+    import_statements::<T>(default_out_dir())
+}
+
+/// Push an import statement for all dependencies of `T`, followed by the aliases of the
+/// dependencies which are declared in a namespace.
 fn generate_imports<T: TS + ?Sized + 'static>(
     out: &mut String,
     out_dir: impl AsRef<Path>,
 ) -> Result<()> {
+    for import in import_statements::<T>(out_dir)? {
+        writeln!(out, "{}", import).unwrap();
+    }
+    writeln!(out).unwrap();
+
+    for alias in namespace_aliases::<T>() {
+        writeln!(out, "{}\n", alias).unwrap();
+    }
+    Ok(())
+}
+
+/// The dependencies of `T` which have to be imported, without `T` itself and without duplicates
+fn imported_dependencies<T: TS + ?Sized + 'static>() -> Vec<Dependency> {
+    // a recursive generic type like `Tree<T>` may depend on another instance of itself, e.g.
+    // `Tree<String>`, which has a different `TypeId` but is declared in the very same file
+    let own_path = T::output_path();
+    T::dependencies()
+        .into_iter()
+        .filter(|dep| dep.type_id != TypeId::of::<T>() && Some(dep.output_path) != own_path)
+        .map(|dep| ((dep.namespace, dep.ts_name.clone()), dep))
+        .collect::<BTreeMap<_, _>>()
+        .into_values()
+        .collect()
+}
+
+/// The import statements for all dependencies of `T`, one per module, sorted by the path of the
+/// module. All types imported from the same module are merged into a single, sorted statement.
+fn import_statements<T: TS + ?Sized + 'static>(out_dir: impl AsRef<Path>) -> Result<Vec<String>> {
     let path = T::output_path()
         .ok_or_else(std::any::type_name::<T>)
         .map_err(Error::CannotBeExported)?;
     let path = out_dir.as_ref().join(path);
 
-    let deps = T::dependencies();
-    let deduplicated_deps = deps
-        .iter()
-        .filter(|dep| dep.type_id != TypeId::of::<T>())
-        .map(|dep| (&dep.ts_name, dep))
-        .collect::<BTreeMap<_, _>>();
-
-    for (_, dep) in deduplicated_deps {
+    // types are imported with `import type`, while aliased namespaces are imported as values
+    // unless `TS_GEN_IMPORT_TYPE` is set, so every module may require up to two statements
+    let namespaces_type_only = type_only_imports();
+    let aliased = aliased_namespaces::<T>();
+    let mut modules = BTreeMap::<(String, bool), BTreeSet<String>>::new();
+    for dep in imported_dependencies::<T>() {
         let dep_path = out_dir.as_ref().join(dep.output_path);
         let rel_path = import_path(&path, &dep_path);
-        writeln!(
-            out,
-            "import type {{ {} }} from {:?};",
-            &dep.ts_name, rel_path
-        )
-        .unwrap();
+        let (type_only, name) = match dep.namespace.map(namespace_root) {
+            // the namespace is imported under an alias, which is unique even if multiple files
+            // declare types in the same namespace
+            Some(root) if aliased.contains(root) => (
+                namespaces_type_only,
+                format!(
+                    "{} as {}",
+                    root,
+                    namespace_alias(dep.namespace.unwrap(), &dep.ts_name)
+                ),
+            ),
+            Some(root) => (true, root.to_owned()),
+            None => (true, dep.ts_name),
+        };
+        modules
+            .entry((rel_path, type_only))
+            .or_default()
+            .insert(name);
     }
-    writeln!(out).unwrap();
-    Ok(())
+
+    Ok(modules
+        .into_iter()
+        .map(|((rel_path, type_only), names)| {
+            let names = names.into_iter().collect::<Vec<_>>().join(", ");
+            let rel_path = crate::string_literal(&rel_path);
+            match type_only {
+                true => format!("import type {{ {} }} from {};", names, rel_path),
+                false => format!("import {{ {} }} from {};", names, rel_path),
+            }
+        })
+        .collect())
+}
+
+/// Reads `TS_GEN_IMPORT_TYPE`. If it is `true`, the aliased namespaces of dependencies are
+/// imported using `import type` as well, as required by `verbatimModuleSyntax`.
+fn type_only_imports() -> bool {
+    matches!(std::env::var("TS_GEN_IMPORT_TYPE").as_deref(), Ok("true"))
+}
+
+/// The outermost namespaces of the dependencies of `T` which cannot be imported directly from the
+/// module declaring them. This is the case if `T` is declared in the same namespace, or if the
+/// namespace is declared by multiple modules.
+fn aliased_namespaces<T: TS + ?Sized + 'static>() -> BTreeSet<&'static str> {
+    let mut modules = BTreeMap::<&str, BTreeSet<&Path>>::new();
+    for dep in imported_dependencies::<T>() {
+        if let Some(namespace) = dep.namespace {
+            modules
+                .entry(namespace_root(namespace))
+                .or_default()
+                .insert(dep.output_path);
+        }
+    }
+
+    let own_root = T::NAMESPACE.map(namespace_root);
+    modules
+        .into_iter()
+        .filter(|(root, modules)| Some(*root) == own_root || modules.len() > 1)
+        .map(|(root, _)| root)
+        .collect()
+}
+
+/// Re-declares every dependency of `T` whose namespace is imported under an alias within a local
+/// namespace of the same name, so the dependencies can be referred to by their qualified name,
+/// e.g. `Api.User`.
+/// If `T` is declared in that namespace as well, TypeScript merges the declarations, so these are
+/// exported. Otherwise, they are local to the module.
+fn namespace_aliases<T: TS + ?Sized + 'static>() -> Vec<String> {
+    let aliased = aliased_namespaces::<T>();
+    let own_root = T::NAMESPACE.map(namespace_root);
+    imported_dependencies::<T>()
+        .into_iter()
+        .filter_map(|dep| {
+            let namespace = dep.namespace?;
+            let root = namespace_root(namespace);
+            if !aliased.contains(root) {
+                return None;
+            }
+
+            let export = match Some(root) == own_root {
+                true => "export ",
+                false => "",
+            };
+            let alias = namespace_alias(namespace, &dep.ts_name);
+            let nested = &namespace[root.len()..];
+            Some(format!(
+                "{}namespace {} {{ export import {} = {}{}.{}; }}",
+                export, namespace, dep.ts_name, alias, nested, dep.ts_name
+            ))
+        })
+        .collect()
+}
+
+/// The outermost namespace of a (possibly nested) namespace like `Api.V1`
+fn namespace_root(namespace: &str) -> &str {
+    namespace.split('.').next().unwrap_or(namespace)
+}
+
+/// The name under which the namespace of a dependency is imported
+fn namespace_alias(namespace: &str, ts_name: &str) -> String {
+    format!("__{}_{}", namespace.replace('.', "_"), ts_name)
 }
 
 /// Returns the required import path for importing `import` from the file `from`
 fn import_path(from: &Path, import: &Path) -> String {
     let rel_path =
         diff_paths(import, from.parent().unwrap()).expect("failed to calculate import path");
+    // components are joined with `/`, since `\` is not a valid separator in import paths
+    let segments = rel_path
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/");
     let path = match rel_path.components().next() {
-        Some(Component::Normal(_)) => format!("./{}", rel_path.to_string_lossy()),
-        _ => rel_path.to_string_lossy().into(),
+        Some(Component::Normal(_)) => format!("./{}", segments),
+        _ => segments,
     };
 
     let path_without_extension = path.trim_end_matches(".ts");
@@ -0,0 +1,40 @@
+//! The prelude is a single file, `__brands.ts`, declaring helper types which are shared by
+//! the generated bindings, like the brand types of the `branded-numbers` feature.
+//!
+//! Helper types return [`PATH`] from `TS::output_path`, so they are imported from the prelude
+//! like any other dependency. Exporting any of them writes the whole prelude. If no helper type is
+//! used, the prelude is never exported.
+
+use std::path::Path;
+
+/// The file, relative to the export directory, in which all helper types are declared
+pub(crate) const PATH: &str = "__brands.ts";
+
+/// Returns whether `path` is the output path of the prelude
+pub(crate) fn is_prelude(path: Option<&Path>) -> bool {
+    path == Some(Path::new(PATH))
+}
+
+/// The declarations of all helper types enabled by the current set of features, without the
+/// `export` keyword. A declaration may be preceded by its JSDoc comment.
+pub(crate) fn declarations() -> Vec<String> {
+    #[allow(unused_mut)]
+    let mut declarations = Vec::new();
+
+    #[cfg(any(feature = "branded-numbers", feature = "branded-char"))]
+    declarations.extend(crate::branded::declarations());
+
+    declarations
+}
+
+/// Push the declarations of all helper types
+pub(crate) fn generate(out: &mut String) {
+    let declarations = declarations()
+        .into_iter()
+        .map(|decl| match decl.rsplit_once('\n') {
+            Some((docs, decl)) => format!("{}\nexport {}", docs, decl),
+            None => format!("export {}", decl),
+        })
+        .collect::<Vec<_>>();
+    out.push_str(&declarations.join("\n\n"));
+}
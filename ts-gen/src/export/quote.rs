@@ -0,0 +1,43 @@
+/// Quotes used for string literals in the generated bindings, configured using the
+/// `TS_GEN_QUOTE_STYLE` environment variable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum QuoteStyle {
+    Double,
+    Single,
+}
+
+impl QuoteStyle {
+    /// Reads `TS_GEN_QUOTE_STYLE`, which may be either `double` (the default) or `single`.
+    pub(crate) fn from_env() -> Self {
+        match std::env::var("TS_GEN_QUOTE_STYLE").as_deref() {
+            Ok("single") => Self::Single,
+            _ => Self::Double,
+        }
+    }
+
+    /// Encloses `value` in quotes of this style, escaping it as necessary.
+    pub(crate) fn quote(self, value: &str) -> String {
+        let quote = match self {
+            Self::Double => '"',
+            Self::Single => '\'',
+        };
+
+        let mut out = String::with_capacity(value.len() + 2);
+        out.push(quote);
+        for c in value.chars() {
+            match c {
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if c == quote => {
+                    out.push('\\');
+                    out.push(c);
+                }
+                c => out.push(c),
+            }
+        }
+        out.push(quote);
+        out
+    }
+}
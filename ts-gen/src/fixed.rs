@@ -0,0 +1,23 @@
+use fixed::{
+    FixedI128, FixedI16, FixedI32, FixedI64, FixedI8, FixedU128, FixedU16, FixedU32, FixedU64,
+    FixedU8,
+};
+
+use super::TS;
+
+// generate impls for fixed-point types, which are generic over the number of fractional bits
+macro_rules! impl_fixed {
+    ($($t:ident),* => $l:literal) => {$(
+        impl<Frac: 'static> TS for $t<Frac> {
+            fn name() -> String { $l.to_owned() }
+            fn decl() -> String { panic!("{} cannot be declared", Self::name()) }
+            fn decl_concrete() -> String { panic!("{} cannot be declared", Self::name()) }
+            fn inline() -> String { Self::name() }
+            fn inline_flattened() -> String { panic!("{} cannot be flattened", Self::name()) }
+        }
+    )*};
+}
+
+impl_fixed!(FixedI8, FixedU8, FixedI16, FixedU16, FixedI32, FixedU32 => "number");
+// 64 and 128 bit fixed-point numbers cannot be represented by a `number` without losing precision
+impl_fixed!(FixedI64, FixedU64, FixedI128, FixedU128 => "string");
@@ -0,0 +1,7 @@
+use jiff::{tz::TimeZone, Zoned};
+
+use super::{impl_primitives, TS};
+
+// `Zoned` is serialized as an RFC 9557 timestamp, e.g. "2024-07-11T01:14:00-04:00[America/New_York]",
+// and `TimeZone` as its IANA name, e.g. "America/New_York"
+impl_primitives!(Zoned, TimeZone => "string");
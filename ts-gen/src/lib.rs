@@ -37,28 +37,46 @@
 //! - support for ESM imports
 //!
 //! ## cargo features
-//! | **Feature**        | **Description**                                                                                                                                                                                           |
-//! |:-------------------|-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
-//! | serde-compat       | **Enabled by default** <br/>See the *"serde compatibility"* section below for more information.                                                                                                           |
-//! | format             | Enables formatting of the generated TypeScript bindings. <br/>Currently, this unfortunately adds quite a few dependencies.                                                                                |
-//! | no-serde-warnings  | By default, warnings are printed during build if unsupported serde attributes are encountered. <br/>Enabling this feature silences these warnings.                                                        |
-//! | import-esm         | When enabled,`import` statements in the generated file will have the `.js` extension in the end of the path to conform to the ES Modules spec. <br/> Example: `import { MyStruct } from "./my_struct.js"` |
-//! | serde-json-impl    | Implement `TS` for types from *serde_json*                                                                                                                                                                |
-//! | chrono-impl        | Implement `TS` for types from *chrono*                                                                                                                                                                    |
-//! | bigdecimal-impl    | Implement `TS` for types from *bigdecimal*                                                                                                                                                                |
-//! | url-impl           | Implement `TS` for types from *url*                                                                                                                                                                       |
-//! | uuid-impl          | Implement `TS` for types from *uuid*                                                                                                                                                                      |
-//! | bson-uuid-impl     | Implement `TS` for types from *bson*                                                                                                                                                                      |
-//! | bytes-impl         | Implement `TS` for types from *bytes*                                                                                                                                                                     |
-//! | indexmap-impl      | Implement `TS` for types from *indexmap*                                                                                                                                                                  |
-//! | ordered-float-impl | Implement `TS` for types from *ordered_float*                                                                                                                                                             |
-//! | heapless-impl      | Implement `TS` for types from *heapless*                                                                                                                                                                  |
-//! | semver-impl        | Implement `TS` for types from *semver*                                                                                                                                                                    |
+//! | **Feature**            | **Description**                                                                                                                                                                                           |
+//! |:-----------------------|-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
+//! | serde-compat           | **Enabled by default** <br/>See the *"serde compatibility"* section below for more information.                                                                                                           |
+//! | format                 | Enables formatting of the generated TypeScript bindings. <br/>Currently, this unfortunately adds quite a few dependencies.                                                                                |
+//! | no-serde-warnings      | By default, warnings are printed during build if unsupported serde attributes are encountered. <br/>Enabling this feature silences these warnings.                                                        |
+//! | import-esm             | When enabled,`import` statements in the generated file will have the `.js` extension in the end of the path to conform to the ES Modules spec. <br/> Example: `import { MyStruct } from "./my_struct.js"` |
+//! | export-module-path     | When no `#[ts(export_to = "..")]` is given, types are exported into subdirectories mirroring their Rust module path. <br/> Example: `crate::api::User` is exported to `api/User.ts`                       |
+//! | parallel-export        | Exported files are written to disk in parallel, which can speed up exporting large schemas. <br/> The generated bindings are the same as without this feature                                             |
+//! | branded-numbers        | Integers and floats are emitted as the branded types `Integer` and `Float`, declared once in `__brands.ts`. <br/> 64 and 128 bit integers are still emitted as `bigint`                           |
+//! | branded-char           | `char` is emitted as the branded type `Char`, declared once in `__brands.ts`, instead of `string`                                                                                                 |
+//! | js-set                 | Sets (`HashSet`, `BTreeSet` and `IndexSet`) are emitted as a JS `Set<T>` instead of `Array<T>`. <br/> Only useful if they are (de)serialized from and to a JS `Set`                                       |
+//! | js-collections         | Like `js-set`, and additionally emits maps (`HashMap`, `BTreeMap` and `IndexMap`) as a JS `Map<K, V>` instead of an object                                                                                |
+//! | chrono-duration-number | `chrono::Duration` is emitted as `number` instead of `string`. <br/> Useful if durations are serialized as a number of seconds or milliseconds, e.g. with *serde_with*                                    |
+//! | time-duration-object   | `time::Duration` is emitted as `{ seconds: number, nanoseconds: number }` instead of `string`                                                                                                             |
+//! | serde-json-impl        | Implement `TS` for types from *serde_json*                                                                                                                                                                |
+//! | chrono-impl            | Implement `TS` for types from *chrono*                                                                                                                                                                    |
+//! | bigdecimal-impl        | Implement `TS` for types from *bigdecimal*                                                                                                                                                                |
+//! | url-impl               | Implement `TS` for types from *url*                                                                                                                                                                       |
+//! | uuid-impl              | Implement `TS` for types from *uuid*                                                                                                                                                                      |
+//! | bson-impl              | Implement `TS` for `ObjectId`, `DateTime` and `Decimal128` from *bson*                                                                                                                                    |
+//! | bson-uuid-impl         | Implement `TS` for `Uuid` from *bson*                                                                                                                                                                     |
+//! | bytes-impl             | Implement `TS` for types from *bytes*                                                                                                                                                                     |
+//! | indexmap-impl          | Implement `TS` for types from *indexmap*                                                                                                                                                                  |
+//! | indexmap-ordered       | Like `indexmap-impl`, but `IndexMap<K, V>` is emitted as an array of entries `Array<[K, V]>` instead of an object. <br/> Useful if maps are serialized as a sequence of entries                           |
+//! | ordered-float-impl     | Implement `TS` for types from *ordered_float*                                                                                                                                                             |
+//! | heapless-impl          | Implement `TS` for types from *heapless*                                                                                                                                                                  |
+//! | semver-impl            | Implement `TS` for types from *semver*                                                                                                                                                                    |
+//! | ipnet-impl             | Implement `TS` for types from *ipnet*                                                                                                                                                                     |
+//! | enumset-impl           | Implement `TS` for types from *enumset*                                                                                                                                                                   |
+//! | fixed-impl             | Implement `TS` for types from *fixed*                                                                                                                                                                     |
+//! | kstring-impl           | Implement `TS` for types from *kstring*                                                                                                                                                                   |
+//! | half-impl              | Implement `TS` for types from *half*                                                                                                                                                                      |
+//! | time-impl              | Implement `TS` for types from *time*                                                                                                                                                                      |
+//! | jiff-impl              | Implement `TS` for types from *jiff*                                                                                                                                                                      |
+//! | bytestring-impl        | Implement `TS` for `ByteString` from *bytestring*                                                                                                                                                         |
 //!
 //! <br/>
 //!
 //! If there's a type you're dealing with which doesn't implement `TS`, use either
-//! `#[ts(as = "..")]` or `#[ts(type = "..")]`, or open a PR.
+//! `#[ts(as = "..")]` or `#[ts(type = "..")]`, implement it with [`impl_ts_as!`], or open a PR.
 //!
 //! ## `serde` compatability
 //! With the `serde-compat` feature (enabled by default), serde attributes can be parsed for enums and structs.
@@ -81,22 +99,37 @@
 use std::{
     any::TypeId,
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    convert::Infallible,
+    ffi::{CStr, CString, OsStr, OsString},
     net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
     num::{
         NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
         NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
     },
-    ops::{Range, RangeInclusive},
+    ops::{Bound, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo},
     path::{Path, PathBuf},
+    sync::atomic::{
+        AtomicBool, AtomicI16, AtomicI32, AtomicI8, AtomicIsize, AtomicU16, AtomicU32, AtomicU8,
+        AtomicUsize,
+    },
 };
 
+#[cfg(any(feature = "branded-numbers", feature = "branded-char"))]
+mod branded;
 #[cfg(feature = "chrono-impl")]
 mod chrono;
 pub mod error;
 mod export;
+#[cfg(feature = "fixed-impl")]
+mod fixed;
 #[cfg(feature = "serde-json-impl")]
 mod serde_json;
+#[cfg(feature = "time-impl")]
+mod time;
+#[cfg(feature = "jiff-impl")]
+mod jiff;
 pub mod typelist;
+pub mod types;
 
 pub use ts_gen_macros::TS;
 
@@ -111,6 +144,8 @@ pub struct Dependency {
     pub type_id: TypeId,
     /// Name of the type in TypeScript
     pub ts_name: String,
+    /// Namespace the type is declared in, see [`Dependency::namespace`]
+    namespace: Option<&'static str>,
     /// Path to where the type would be exported. By default, a filename is derived from the types
     /// name, which can be customized with `#[ts(export_to = "..")]`.
     /// This path does _not_ include a base directory.
@@ -125,9 +160,23 @@ impl Dependency {
         Some(Dependency {
             type_id: TypeId::of::<T>(),
             ts_name: T::ident(),
+            namespace: T::NAMESPACE,
             output_path,
         })
     }
+
+    /// Namespace the type is declared in, see [`TS::NAMESPACE`]
+    pub fn namespace(&self) -> Option<&'static str> {
+        self.namespace
+    }
+}
+
+/// Encloses `value` in quotes, producing a TypeScript string literal.
+/// Double quotes are used, unless the `TS_GEN_QUOTE_STYLE` environment variable is set to
+/// `single`. All string literals in the generated bindings are emitted using this function, so
+/// manual implementations of [`TS`] should use it as well.
+pub fn string_literal(value: &str) -> String {
+    export::quote::QuoteStyle::from_env().quote(value)
 }
 
 /// A type which can be represented in TypeScript.
@@ -150,20 +199,32 @@ impl Dependency {
 /// For each individual type, path and filename within the output directory can be changed using
 /// `#[ts(export_to = "...")]`. By default, the filename will be derived from the name of the type.
 ///
+/// String literals in the generated bindings are enclosed in double quotes. To use single quotes
+/// instead, set the `TS_GEN_QUOTE_STYLE` environment variable to `single`.
+/// Exported files use `\n` line endings, unless `TS_GEN_LINE_ENDING` is set to `crlf`.
+/// Dependencies are imported using `import type`. Namespaces which have to be imported under an
+/// alias are the exception, since they are imported as a value, unless `TS_GEN_IMPORT_TYPE` is set
+/// to `true`.
+/// If `TS_GEN_INDEX` is set to `true`, an `index.ts` file re-exporting every exported module is
+/// written into the export directory as well. It is updated whenever a type is exported together
+/// with its dependencies, e.g. using [`TS::export_all`]. Re-exports of modules which no longer exist
+/// are removed from the index, so renamed or deleted types don't leave dangling entries behind.
+///
 /// If, for some reason, you need to do this during runtime or cannot use `#[ts(export)]`, bindings
 /// can be exported manually:
 ///
-/// | Function              | Includes Dependencies | To                 |
-/// |-----------------------|-----------------------|--------------------|
-/// | [`TS::export`]        | ❌                    | `TS_GEN_EXPORT_DIR` |
-/// | [`TS::export_all`]    | ✔️                    | `TS_GEN_EXPORT_DIR` |
-/// | [`TS::export_all_to`] | ✔️                    | _custom_           |
+/// | Function                 | Includes Dependencies | To                  |
+/// |--------------------------|-----------------------|---------------------|
+/// | [`TS::export`]           | ❌                    | `TS_GEN_EXPORT_DIR` |
+/// | [`TS::export_all`]       | ✔️                    | `TS_GEN_EXPORT_DIR` |
+/// | [`TS::export_all_to`]    | ✔️                    | _custom_            |
+/// | [`TS::export_bundle_to`] | ✔️                    | _custom_, one file  |
 ///
 /// ### serde compatibility
 /// By default, the feature `serde-compat` is enabled.
 /// ts-gen then parses serde attributes and adjusts the generated typescript bindings accordingly.
 /// Not all serde attributes are supported yet - if you use an unsupported attribute, you'll see a
-/// warning.
+/// warning, or an error if the type is marked with `#[ts(strict)]`.
 ///
 /// ### container attributes
 /// attributes applicable for both structs and enums
@@ -185,13 +246,21 @@ impl Dependency {
 ///   <br/>
 ///
 /// - **`#[ts(export_to = "..")]`**
-///   Specifies where the type should be exported to. Defaults to `<name>.ts`.
+///   Specifies where the type should be exported to. Defaults to `<name>.ts`, or to
+///   `<module path>/<name>.ts` if the `export-module-path` feature is enabled.
 ///   The path given to the `export_to` attribute is relative to the `TS_GEN_EXPORT_DIR` environment variable,
 ///   or, if `TS_GEN_EXPORT_DIR` is not set, to `./bindings`
 ///   If the provided path ends in a trailing `/`, it is interpreted as a directory.
+///   `\` may be used as separator as well, and is treated just like `/` on every platform.
 ///   Note that you need to add the `export` attribute as well, in order to generate a test which exports the type.
 ///   <br/><br/>
 ///
+/// - **`#[ts(file_name = "..")]`**
+///   Sets the name of the file the type is exported to, without the `.ts` extension, instead of
+///   deriving it from the typescript name of the type. This allows keeping the Rust name for the
+///   file while using `rename`, or the other way around. Cannot be used if `export_to` is a file.
+///   <br/><br/>
+///
 /// - **`#[ts(as = "..")]`**
 ///   Overrides the type used in Typescript, using the provided Rust type instead.
 ///   This is useful when you have a custom serializer and deserializer and don't want to implement `TS` manually
@@ -206,6 +275,36 @@ impl Dependency {
 ///   Sets the typescript name of the generated type
 ///   <br/><br/>
 ///
+/// - **`#[ts(name_format = "..")]`**
+///   Derives the typescript name of the generated type from its Rust name, replacing `{}` with it.
+///   E.g. `#[ts(name_format = "Api{}")]` turns `User` into `ApiUser`. `#[ts(rename = "..")]` takes precedence.
+///   <br/><br/>
+///
+/// - **`#[ts(jsdoc_markdown)]`**
+///   Convert the markdown in doc comments of this type and its fields to JSDoc syntax.
+///   Fenced code blocks are emitted as `@example` blocks and links as `{@link}` tags.
+///   <br/><br/>
+///
+/// - **`#[ts(no_docs)]`**
+///   Do not emit doc comments of this type and its fields as JSDoc.
+///   <br/><br/>
+///
+/// - **`#[ts(strict)]`**
+///   Turns the warnings about unsupported serde attributes on this type, its fields and its
+///   variants into errors, so the bindings cannot silently diverge from what serde produces.
+///   <br/><br/>
+///
+/// - **`#[ts(global)]`**
+///   Declare the type in the global scope when exporting it, wrapping its declaration in
+///   `declare global { .. }`. An empty `export {}` is emitted as well, to make the file a module.
+///   <br/><br/>
+///
+/// - **`#[ts(namespace = "..")]`**
+///   Declare the type in a namespace when exporting it, wrapping its declaration in
+///   `export namespace Api { .. }`. Other types refer to it by its qualified name, e.g. `Api.User`.
+///   Nested namespaces like `Api.V1` are supported as well.
+///   <br/><br/>
+///
 /// - **`#[ts(rename_all = "..")]`**
 ///   Rename all fields/variants of the type.
 ///   Valid values are `lowercase`, `UPPERCASE`, `camelCase`, `snake_case`, `PascalCase`, `SCREAMING_SNAKE_CASE`, "kebab-case" and "SCREAMING-KEBAB-CASE"
@@ -216,10 +315,47 @@ impl Dependency {
 ///   Include the structs name (or value of `#[ts(rename = "..")]`) as a field with the given key.
 ///   <br/><br/>
 ///
+/// - **`#[ts(interface)]`**
+///   Declares the struct as `interface Name { .. }` instead of `type Name = { .. };`, allowing
+///   declaration merging. Only applicable to structs with named fields, none of which are flattened.
+///   <br/><br/>
+///
+/// - **`#[ts(readonly)]`**
+///   Marks every field of the struct as `readonly`, just like `#[ts(readonly)]` on each field.
+///   <br/><br/>
+///
+/// - **`#[ts(readonly_deep)]`**
+///   Wraps the generated type in `Readonly<..>`, marking all of its properties as `readonly`, also
+///   if the type is flattened into another one. Like `Readonly<..>`, this is shallow: nested objects
+///   remain mutable, unless their types use `readonly_deep` as well.
+///   <br/><br/>
+///
+/// - **`#[ts(optional)]`**
+///   Applies `#[ts(optional)]` (or `#[ts(optional = nullable)]`, `#[ts(optional = full)]`) to every
+///   field of type `Option<T>`. Fields marked with `#[ts(optional)]` themselves keep their own mode.
+///   <br/><br/>
+///
+/// - **`#[ts(field_names_union)]`**
+///   Additionally emits `type <name>Fields = "a" | "b" | ..`, a union of the (renamed) field names.
+///   Fields of flattened types are not included.
+///   <br/><br/>
+///
+/// - **`#[ts(transparent)]`**
+///   Generates an alias for the type of the struct's single field, like `#[serde(transparent)]`.
+///   This does not require the `serde-compat` feature.
+///   <br/><br/>
+///
+/// - **`#[ts(tuple_labels = "..")]`**
+///   Labels the elements of a tuple struct, e.g. `#[ts(tuple_labels = "x, y")]` generates
+///   `[x: number, y: number]`. There must be exactly one label for each field which is not skipped.
+///   <br/><br/>
+///
 /// ### struct field attributes
 /// - **`#[ts(type = "..")]`**
 ///   Overrides the type used in TypeScript.
 ///   This is useful when there's a type for which you cannot derive `TS`.
+///   `{T}` may be used to refer to the generic parameter `T`, e.g. `#[ts(type = "{T}[keyof {T}]")]`.
+///   All other braces, like those of object or mapped types, are emitted as they are.
 ///   <br/><br/>
 ///
 /// - **`#[ts(as = "..")]`**
@@ -244,10 +380,31 @@ impl Dependency {
 ///   May be applied on a struct field of type `Option<T>`. By default, such a field would turn into `t: T | null`.
 ///   If `#[ts(optional)]` is present, `t?: T` is generated instead.
 ///   If `#[ts(optional = nullable)]` is present, `t?: T | null` is generated.
+///   If `#[ts(optional = full)]` is present, `t?: T | null | undefined` is generated.
+///   This matches fields with `#[serde(default)]`, which may be omitted entirely when deserializing.
+///   <br/><br/>
+///
+/// - **`#[ts(default = "..")]`**
+///   Documents the default value of the field, adding a `@default ..` tag to its JSDoc, e.g.
+///   `#[ts(default = "30")]`. Useful together with `#[ts(optional)]` or `#[serde(default)]`.
 ///   <br/><br/>
 ///
 /// - **`#[ts(flatten)]`**
 ///   Flatten this field, inlining all the keys of the field's type into its parent.
+///   If the field's type is a generic parameter `T`, the declaration is intersected with `T`.
+///   <br/><br/>
+///
+/// - **`#[ts(readonly)]`**
+///   Marks the field as `readonly`, e.g. `readonly id: number`. Flattened fields are wrapped in
+///   `Readonly<..>` instead.
+///   For slices, like `&[T]`, `Box<[T]>` or `Arc<[T]>`, `ReadonlyArray<T>` is generated instead of
+///   `Array<T>` as well.
+///   <br/><br/>
+///
+/// - **`#[ts(tuple_labels = "..")]`**
+///   Labels the elements of a field which is a tuple or an array of a fixed length, e.g.
+///   `#[ts(tuple_labels = "r, g, b")]` on a `[u8; 3]` generates `[r: number, g: number, b: number]`.
+///   The number of labels must match the number of elements.
 ///   <br/><br/>
 ///
 /// ### enum attributes
@@ -266,6 +423,83 @@ impl Dependency {
 ///   See [the serde docs](https://serde.rs/enum-representations.html) for more information.
 ///   <br/><br/>
 ///
+/// - **`#[ts(optional)]`**
+///   Applies `#[ts(optional)]` to every field of type `Option<T>` within the struct variants of the
+///   enum, just like `#[ts(optional)]` on a struct.
+///   <br/><br/>
+///
+/// - **`#[ts(add_discriminant = "..")]`**
+///   Adds a synthetic discriminant to every variant of an untagged enum, e.g.
+///   `#[ts(untagged, add_discriminant = "_tag")]` generates `{ "_tag": "Circle" } & Circle | ..`,
+///   which allows TypeScript to narrow the union.
+///   Since serde does not serialize the discriminant, a `@remarks` tag noting this is added to the
+///   docs of the enum.
+///   <br/><br/>
+///
+/// - **`#[ts(extractors)]`**
+///   Additionally generates a type `<Enum>_<Variant>` for every variant, which narrows the enum
+///   down to that variant, e.g. `type Event_Click = Extract<Event, { "Click": unknown }>`.
+///   Not applicable to untagged enums.
+///   <br/><br/>
+///
+/// - **`#[ts(non_exhaustive)]`**
+///   Adds a member to the union which matches variants added in the future, so consumers have to
+///   handle unknown values. This is `(string & {})` if all variants are unit variants, and
+///   `{ [key: string]: unknown }` otherwise.
+///   Enums marked with `#[non_exhaustive]` behave as if this attribute was present.
+///   <br/><br/>
+///
+/// - **`#[ts(event_map = "..")]`**
+///   Additionally generates a type with the given name, which maps the name of every variant to
+///   its data, e.g. `type EventMap = { click: ClickData, hover: HoverData, };`.
+///   This allows for `addEventListener`-style APIs like
+///   `on<K extends keyof EventMap>(event: K, listener: (data: EventMap[K]) => void)`.
+///   <br/><br/>
+///
+/// - **`#[ts(variants_as_intersection)]`**
+///   May be applied on an internally tagged enum. The data of every struct variant is declared as
+///   a separate type `<Enum><Variant>Data`, and the variant itself becomes
+///   `{ "tag": "Variant" } & EnumVariantData`.
+///   Since the data types are only declared next to the enum, they are not used where the enum is
+///   inlined or flattened.
+///   <br/><br/>
+///
+/// - **`#[ts(example_switch)]`**
+///   May be applied on an internally or adjacently tagged enum. Appends an `@example` block to the
+///   JSDoc of the enum, showing a `switch` over the tag with a `case` for every variant.
+///   Since the docs are generated at compile time, the example always uses double quotes,
+///   regardless of `TS_GEN_QUOTE_STYLE`.
+///   <br/><br/>
+///
+/// - **`#[ts(hierarchy)]`**
+///   May be applied on an internally tagged enum with unit and struct variants. Additionally
+///   generates a base interface `<Enum>Base` containing the tag, and an interface
+///   `<Enum><Variant>` extending it for every variant, e.g.
+///   `interface ShapeCircle extends ShapeBase { kind: "Circle", radius: number, }`.
+///   These interfaces are only declared next to the enum. The enum itself does not refer to them,
+///   so it can still be inlined or flattened into other types.
+///   <br/><br/>
+///
+/// - **`#[ts(enum)]`**
+///   May be applied on an enum whose variants have no fields. Instead of a union of string
+///   literals, the enum is declared as a TypeScript `enum`, e.g.
+///   `enum Color { Red = "red", Green = "green" }`. The values of the members respect
+///   `#[ts(rename)]` and `#[ts(rename_all = "..")]`.
+///   <br/><br/>
+///
+/// - **`#[ts(enum = "numeric")]`**
+///   Like `#[ts(enum)]`, but the members are initialized with the discriminants of the variants,
+///   e.g. `enum Level { Low = 1, High = 5 }`, for enums which are serialized as a number.
+///   Variants without an explicit discriminant continue counting from the previous one, starting
+///   at 0, like in Rust. Only integer literals are supported as discriminants.
+///   <br/><br/>
+///
+/// - **`#[ts(result_shape)]`**
+///   May be applied on an enum with exactly two newtype variants, like `Ok(T)` and `Err(E)`.
+///   Instead of a tagged union, `{ success: true, data: T } | { success: false, error: E }` is
+///   generated, with the first variant being the successful one.
+///   <br/><br/>
+///
 /// - **`#[ts(rename_all = "..")]`**
 ///   Rename all variants of this enum.
 ///   Valid values are `lowercase`, `UPPERCASE`, `camelCase`, `snake_case`, `PascalCase`, `SCREAMING_SNAKE_CASE`, "kebab-case" and "SCREAMING-KEBAB-CASE"
@@ -297,9 +531,23 @@ impl Dependency {
 ///   <br/><br/>
 pub trait TS {
     /// JSDoc comment to describe this type in TypeScript - when `TS` is derived, docs are
-    /// automatically read from your doc comments or `#[doc = ".."]` attributes
+    /// automatically read from your doc comments or `#[doc = ".."]` attributes, and a
+    /// `@deprecated` tag is added for `#[deprecated]` types
     const DOCS: Option<&'static str> = None;
 
+    /// Whether this type is declared in the global scope, using `declare global { .. }`, when
+    /// exported. When `TS` is derived, this is set using `#[ts(global)]`.
+    const GLOBAL: bool = false;
+
+    /// The namespace this type is declared in, using `export namespace .. { .. }`, when exported.
+    /// When `TS` is derived, this is set using `#[ts(namespace = "..")]`.
+    const NAMESPACE: Option<&'static str> = None;
+
+    /// Whether this type is a union of string or number literals, like an enum with only unit
+    /// variants. TypeScript does not accept such a type as the key of an index signature, so maps
+    /// with such keys are emitted as a mapped type, e.g. `{ [key in Color]?: number }`.
+    const LITERAL_UNION: bool = false;
+
     /// Name of this type in TypeScript, including generic parameters
     fn name() -> String;
 
@@ -328,6 +576,21 @@ pub trait TS {
     /// If this type is not generic, then this function is equivalent to `TS::decl()`.
     fn decl_concrete() -> String;
 
+    /// Declaration of this type, just like [`TS::decl`], but using `name` instead of the name of
+    /// the type, e.g. `type Alias = { user_id: number, ... }`.
+    /// This is useful for emitting multiple aliases of the same shape.
+    /// This function will panic if the type has no declaration.
+    fn decl_named(name: &str) -> String {
+        panic!("{} cannot be declared as {}", Self::name(), name)
+    }
+
+    /// Additional declarations which are emitted after [`TS::decl`] when this type is exported,
+    /// e.g. the aliases generated by `#[ts(extractors)]`.
+    /// Just like [`TS::decl`], these declarations do not contain the `export` keyword.
+    fn helper_decls() -> Vec<String> {
+        vec![]
+    }
+
     /// Formats this types definition in TypeScript, e.g `{ user_id: number }`.
     /// This function will panic if the type cannot be inlined.
     fn inline() -> String;
@@ -443,8 +706,22 @@ pub trait TS {
         export::export_all_into::<Self>(out_dir)
     }
 
+    /// Manually export this type together with all of its dependencies into a single file, like
+    /// `bindings.ts`. Since all declarations live in the same file, no imports are generated.
+    /// Dependencies are declared before the types using them.
+    ///
+    /// Unlike [`TS::export_all_to`], this function disregards `#[ts(export_to = "...")]`, using
+    /// the provided file instead.
+    fn export_bundle_to(path: impl AsRef<Path>) -> Result<()>
+    where
+        Self: 'static,
+    {
+        export::export_bundle_to::<Self, _>(path)
+    }
+
     /// Manually generate bindings for this type, returning a [`String`].
-    /// This function does not format the output, even if the `format` feature is enabled.
+    /// This function does not format the output, even if the `format` feature is enabled. Use
+    /// [`TS::export_to_string_formatted`] to get the formatted output instead.
     ///
     /// # Automatic Exporting
     /// Types annotated with `#[ts(export)]`, together with all of their dependencies, will be
@@ -457,6 +734,29 @@ pub trait TS {
         export::export_to_string::<Self>()
     }
 
+    /// Manually generate bindings for this type, returning a [`String`] with the same content as
+    /// the file written by [`TS::export`]. If the `format` feature is enabled, the output is
+    /// formatted.
+    fn export_to_string_formatted() -> Result<String>
+    where
+        Self: 'static,
+    {
+        export::export_to_string_formatted::<Self>()
+    }
+
+    /// Returns the import statements required by the declaration of this type, one per imported
+    /// module and sorted by its path. Types imported from the same module are merged into a
+    /// single statement. Together with [`TS::decl`], this allows assembling output files manually.
+    ///
+    /// The import paths are relative to the output path of this type, and end in `.js` if the
+    /// `import-esm` feature is enabled.
+    fn imports() -> Result<Vec<String>>
+    where
+        Self: 'static,
+    {
+        export::imports::<Self>()
+    }
+
     // Returns the output path to where `T` should be exported.
     /// The returned path does _not_ include the base directory from `TS_GEN_EXPORT_DIR`.
     ///
@@ -490,6 +790,39 @@ pub trait TS {
     fn default_output_path() -> Option<PathBuf> {
         Some(export::default_out_dir().join(Self::output_path()?))
     }
+
+    /// Returns the output path to where `T` would be exported, using `base` as base directory.
+    ///
+    /// Unlike [`TS::default_output_path`], this does not read the environment variable
+    /// `TS_GEN_EXPORT_DIR`, which makes it useful for computing paths within an arbitrary
+    /// directory, e.g. in a build script.
+    ///
+    /// If `T` cannot be exported (e.g. because it's a primitive type), this function will return
+    /// `None`.
+    fn output_path_in(base: &Path) -> Option<PathBuf> {
+        Some(base.join(Self::output_path()?))
+    }
+
+    /// Returns whether `T` can be exported to its own file, which is the case if
+    /// [`TS::output_path`] returns `Some`.
+    ///
+    /// Primitives and built-in types like `Vec<T>` cannot be exported, so calling [`TS::export`]
+    /// or [`TS::decl`] on them fails. With the `branded-numbers` and `branded-char` features,
+    /// numbers and `char` are the exception: they are exported into the prelude declaring their
+    /// brand types, even though they are still primitive, see [`TS::is_primitive`].
+    fn can_be_exported() -> bool {
+        Self::output_path().is_some()
+    }
+
+    /// Returns whether `T` is represented by a built-in TypeScript type, like `number` or
+    /// `string`, which is the case for primitives like `u32`, `String` and `bool`.
+    ///
+    /// Wrappers like `Box<T>` or `&T` are primitive if `T` is. Numbers and `char` remain primitive
+    /// with the `branded-numbers` and `branded-char` features, even though they are emitted as
+    /// brand types.
+    fn is_primitive() -> bool {
+        false
+    }
 }
 
 // generate impls for primitive types
@@ -501,6 +834,7 @@ macro_rules! impl_primitives {
             fn decl_concrete() -> String { panic!("{} cannot be declared", <Self as $crate::TS>::name()) }
             fn inline() -> String { <Self as $crate::TS>::name() }
             fn inline_flattened() -> String { panic!("{} cannot be flattened", <Self as $crate::TS>::name()) }
+            fn is_primitive() -> bool { true }
         }
     )*)* };
 }
@@ -524,6 +858,12 @@ macro_rules! impl_tuples {
             {
                 ()$(.push::<$i>())*
             }
+            fn generics() -> impl TypeList
+            where
+                Self: 'static
+            {
+                ()$(.extend($i::generics()).push::<$i>())*
+            }
         }
     };
     ( $i2:ident $(, $i:ident)* ) => {
@@ -537,11 +877,14 @@ macro_rules! impl_tuples {
 macro_rules! impl_wrapper {
     ($($t:tt)*) => {
         $($t)* {
+            const LITERAL_UNION: bool = T::LITERAL_UNION;
+
             fn name() -> String { T::name() }
             fn decl() -> String { panic!("wrapper type cannot be declared") }
             fn decl_concrete() -> String { panic!("wrapper type cannot be declared") }
             fn inline() -> String { T::inline() }
             fn inline_flattened() -> String { T::inline_flattened() }
+            fn is_primitive() -> bool { T::is_primitive() }
             fn dependency_types() -> impl $crate::typelist::TypeList
             where
                 Self: 'static
@@ -565,9 +908,11 @@ macro_rules! impl_shadow {
             fn name() -> String { <$s>::name() }
             fn ident() -> String { <$s>::ident() }
             fn decl() -> String { <$s>::decl() }
+            fn decl_named(name: &str) -> String { <$s>::decl_named(name) }
             fn decl_concrete() -> String { <$s>::decl_concrete() }
             fn inline() -> String { <$s>::inline() }
             fn inline_flattened() -> String { <$s>::inline_flattened() }
+            fn is_primitive() -> bool { <$s>::is_primitive() }
             fn dependency_types() -> impl $crate::typelist::TypeList
             where
                 Self: 'static
@@ -585,9 +930,84 @@ macro_rules! impl_shadow {
     };
 }
 
+/// Implement [`TS`] for a type which is serialized like another type implementing [`TS`].
+///
+/// ```
+/// # use ts_gen::{impl_ts_as, TS};
+/// // serialized as a list of edges
+/// struct Graph {
+///     edges: Vec<(usize, usize)>,
+/// }
+///
+/// impl_ts_as!(Graph => Vec<(usize, usize)>);
+///
+/// assert_eq!(Graph::name(), "Array<[number, number]>");
+/// ```
+///
+/// This also covers scalars of GraphQL servers like *async-graphql* or *juniper*, which are
+/// usually newtypes serialized as a string or a number. They are emitted just like the primitive,
+/// without any dependencies:
+/// ```
+/// # use ts_gen::{impl_ts_as, TS};
+/// struct Email(String);
+///
+/// impl_ts_as!(Email => String);
+///
+/// assert_eq!(Email::name(), "string");
+/// assert!(Email::dependencies().is_empty());
+/// ```
+///
+/// Generic types, like the quantities of *uom*, are supported by declaring their generic
+/// parameters, optionally followed by a `where` clause:
+/// ```
+/// # use ts_gen::{impl_ts_as, TS};
+/// # mod uom { pub mod si {
+/// #     pub trait Dimension {}
+/// #     pub struct Quantity<D: Dimension + ?Sized, V> { pub value: V, pub dimension: std::marker::PhantomData<D> }
+/// # } }
+/// // serialized as its value in base units
+/// impl_ts_as!(impl<D, V> uom::si::Quantity<D, V> => V where D: uom::si::Dimension + ?Sized, V: TS);
+/// ```
+#[macro_export]
+macro_rules! impl_ts_as {
+    (@impl [$($head:tt)*] $s:ty) => {
+        $($head)* {
+            fn name() -> String { <$s as $crate::TS>::name() }
+            fn ident() -> String { <$s as $crate::TS>::ident() }
+            fn decl() -> String { <$s as $crate::TS>::decl() }
+            fn decl_named(name: &str) -> String { <$s as $crate::TS>::decl_named(name) }
+            fn decl_concrete() -> String { <$s as $crate::TS>::decl_concrete() }
+            fn inline() -> String { <$s as $crate::TS>::inline() }
+            fn inline_flattened() -> String { <$s as $crate::TS>::inline_flattened() }
+            fn is_primitive() -> bool { <$s as $crate::TS>::is_primitive() }
+            fn dependency_types() -> impl $crate::typelist::TypeList
+            where
+                Self: 'static
+            {
+                <$s as $crate::TS>::dependency_types()
+            }
+            fn generics() -> impl $crate::typelist::TypeList
+            where
+                Self: 'static
+            {
+                <$s as $crate::TS>::generics()
+            }
+            fn output_path() -> Option<&'static std::path::Path> {
+                <$s as $crate::TS>::output_path()
+            }
+        }
+    };
+    (impl<$($g:ident),+ $(,)?> $ty:ty => $s:ty $(where $($bound:tt)+)?) => {
+        $crate::impl_ts_as!(@impl [impl<$($g),+> $crate::TS for $ty $(where $($bound)+)?] $s);
+    };
+    ($($ty:ty => $s:ty),+ $(,)?) => { $(
+        $crate::impl_ts_as!(@impl [impl $crate::TS for $ty] $s);
+    )+ };
+}
+
 impl<T: TS> TS for Option<T> {
     fn name() -> String {
-        format!("{} | null", T::name())
+        collapse_null(format!("{} | null", T::name()))
     }
     fn decl() -> String {
         panic!("{} cannot be declared", Self::name())
@@ -598,7 +1018,7 @@ impl<T: TS> TS for Option<T> {
     }
 
     fn inline() -> String {
-        format!("{} | null", T::inline())
+        collapse_null(format!("{} | null", T::inline()))
     }
 
     fn inline_flattened() -> String {
@@ -695,6 +1115,56 @@ impl<T: TS> TS for Vec<T> {
     }
 }
 
+// Sets are emitted as `Array<T>`, or as a JS `Set<T>` with the `js-set` feature
+#[cfg(not(feature = "js-set"))]
+type SetShadow<T> = Vec<T>;
+#[cfg(feature = "js-set")]
+type SetShadow<T> = JsSet<T>;
+
+#[cfg(feature = "js-set")]
+struct JsSet<T>(std::marker::PhantomData<T>);
+
+#[cfg(feature = "js-set")]
+impl<T: TS> TS for JsSet<T> {
+    fn name() -> String {
+        format!("Set<{}>", T::name())
+    }
+
+    fn ident() -> String {
+        "Set".to_owned()
+    }
+
+    fn decl() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn decl_concrete() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn inline() -> String {
+        format!("Set<{}>", T::inline())
+    }
+
+    fn inline_flattened() -> String {
+        panic!("{} cannot be flattened", Self::name())
+    }
+
+    fn dependency_types() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        T::dependency_types()
+    }
+
+    fn generics() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        T::generics().push::<T>()
+    }
+}
+
 // Arrays longer than this limit will be emitted as Array<T>
 const ARRAY_TUPLE_LIMIT: usize = 64;
 impl<T: TS, const N: usize> TS for [T; N] {
@@ -747,13 +1217,44 @@ impl<T: TS, const N: usize> TS for [T; N] {
     }
 }
 
+// Removes repeated ` | null` suffixes, as produced by nested `Option`s
+fn collapse_null(mut ts: String) -> String {
+    while ts.ends_with(" | null | null") {
+        ts.truncate(ts.len() - " | null".len());
+    }
+    ts
+}
+
+// Index signatures only accept `string`, `number` or `symbol` (or aliases of them) as key
+fn map_key(key: String) -> String {
+    #[cfg(any(feature = "branded-numbers", feature = "branded-char"))]
+    let key = branded::unbrand(key);
+    key
+}
+
+// Maps are emitted as an index signature, or as a JS `Map<K, V>` with the `js-collections` feature.
+// If the key is a union of literals, a mapped type is used instead, in which every key is optional.
+fn map_type<K: TS + ?Sized>(key: String, value: String) -> String {
+    let value = collapse_null(value);
+    if cfg!(feature = "js-collections") {
+        return format!("Map<{}, {}>", key, value);
+    }
+    match K::LITERAL_UNION {
+        true => format!("{{ [key in {}]?: {} }}", key, value),
+        false => format!("{{ [key: {}]: {} }}", map_key(key), value),
+    }
+}
+
 impl<K: TS, V: TS, S> TS for HashMap<K, V, S> {
     fn name() -> String {
-        format!("{{ [key: {}]: {} }}", K::name(), V::name())
+        map_type::<K>(K::name(), V::name())
     }
 
     fn ident() -> String {
-        panic!()
+        match cfg!(feature = "js-collections") {
+            true => "Map".to_owned(),
+            false => "Record".to_owned(),
+        }
     }
 
     fn decl() -> String {
@@ -765,7 +1266,7 @@ impl<K: TS, V: TS, S> TS for HashMap<K, V, S> {
     }
 
     fn inline() -> String {
-        format!("{{ [key: {}]: {} }}", K::inline(), V::inline())
+        map_type::<K>(K::inline(), V::inline())
     }
 
     fn inline_flattened() -> String {
@@ -823,9 +1324,148 @@ impl<I: TS> TS for Range<I> {
     }
 }
 
+// generate impls for ranges with a single bound, which serde serializes as an object containing
+// just that bound
+macro_rules! impl_half_open_range {
+    ($($ty:ident => $bound:literal),*) => { $(
+        impl<I: TS> TS for $ty<I> {
+            fn name() -> String {
+                format!("{{ {}: {}, }}", $bound, I::name())
+            }
+
+            fn decl() -> String {
+                panic!("{} cannot be declared", Self::name())
+            }
+
+            fn decl_concrete() -> String {
+                panic!("{} cannot be declared", Self::name())
+            }
+
+            fn inline() -> String {
+                panic!("{} cannot be inlined", Self::name())
+            }
+
+            fn inline_flattened() -> String {
+                panic!("{} cannot be flattened", Self::name())
+            }
+
+            fn dependency_types() -> impl TypeList
+            where
+                Self: 'static,
+            {
+                I::dependency_types()
+            }
+
+            fn generics() -> impl TypeList
+            where
+                Self: 'static,
+            {
+                I::generics().push::<I>()
+            }
+        }
+    )* };
+}
+
+impl_half_open_range!(RangeFrom => "start", RangeTo => "end");
+
+// serialized by serde as an object containing the whole seconds and the remaining nanoseconds.
+// `secs` is emitted like the `u32` nanoseconds, and not as a `bigint`, since durations fit into a
+// JS number
+impl TS for std::time::Duration {
+    fn name() -> String {
+        format!("{{ secs: {}, nanos: {}, }}", u32::name(), u32::name())
+    }
+
+    fn decl() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn decl_concrete() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn inline() -> String {
+        Self::name()
+    }
+
+    fn inline_flattened() -> String {
+        panic!("{} cannot be flattened", Self::name())
+    }
+
+    fn dependency_types() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        // with `branded-numbers`, the brand types are imported from the prelude
+        ().push::<u32>()
+    }
+
+    fn generics() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        // like for `Range<u32>`, types referencing `Self` by name depend on the fields as well
+        ().push::<u32>()
+    }
+}
+
+// serialized by serde as an externally tagged enum
+impl<T: TS> TS for Bound<T> {
+    fn name() -> String {
+        format!(
+            "{{ {}: {} }} | {{ {}: {} }} | {}",
+            string_literal("Included"),
+            T::name(),
+            string_literal("Excluded"),
+            T::name(),
+            string_literal("Unbounded")
+        )
+    }
+
+    fn decl() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn decl_concrete() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn inline() -> String {
+        format!(
+            "{{ {}: {} }} | {{ {}: {} }} | {}",
+            string_literal("Included"),
+            T::inline(),
+            string_literal("Excluded"),
+            T::inline(),
+            string_literal("Unbounded")
+        )
+    }
+
+    fn inline_flattened() -> String {
+        panic!("{} cannot be flattened", Self::name())
+    }
+
+    fn dependency_types() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        T::dependency_types()
+    }
+
+    fn generics() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        T::generics().push::<T>()
+    }
+}
+
 impl_shadow!(as Range<I>: impl<I: TS> TS for RangeInclusive<I>);
-impl_shadow!(as Vec<T>: impl<T: TS, H> TS for HashSet<T, H>);
-impl_shadow!(as Vec<T>: impl<T: TS> TS for BTreeSet<T>);
+impl_shadow!(as SetShadow<T>: impl<T: TS, H> TS for HashSet<T, H>);
+impl_shadow!(as SetShadow<T>: impl<T: TS> TS for BTreeSet<T>);
+impl_shadow!(as Vec<T>: impl<T: TS> TS for std::collections::VecDeque<T>);
+impl_shadow!(as Vec<T>: impl<T: TS + Ord> TS for std::collections::BinaryHeap<T>);
+impl_shadow!(as Vec<T>: impl<T: TS> TS for std::collections::LinkedList<T>);
 impl_shadow!(as HashMap<K, V>: impl<K: TS, V: TS> TS for BTreeMap<K, V>);
 impl_shadow!(as Vec<T>: impl<T: TS> TS for [T]);
 
@@ -837,24 +1477,38 @@ impl_wrapper!(impl<'a, T: TS + ToOwned + ?Sized> TS for std::borrow::Cow<'a, T>)
 impl_wrapper!(impl<T: TS> TS for std::cell::Cell<T>);
 impl_wrapper!(impl<T: TS> TS for std::cell::RefCell<T>);
 impl_wrapper!(impl<T: TS> TS for std::sync::Mutex<T>);
+impl_wrapper!(impl<T: TS> TS for std::sync::RwLock<T>);
+impl_wrapper!(impl<T: TS> TS for std::sync::OnceLock<T>);
 impl_wrapper!(impl<T: TS + ?Sized> TS for std::sync::Weak<T>);
 impl_wrapper!(impl<T: TS> TS for std::marker::PhantomData<T>);
 
 impl_tuples!(T1, T2, T3, T4, T5, T6, T7, T8, T9, T10);
 
+#[cfg(not(feature = "branded-numbers"))]
+impl_primitives! {
+    u8, i8, NonZeroU8, NonZeroI8, AtomicU8, AtomicI8,
+    u16, i16, NonZeroU16, NonZeroI16, AtomicU16, AtomicI16,
+    u32, i32, NonZeroU32, NonZeroI32, AtomicU32, AtomicI32,
+    usize, isize, NonZeroUsize, NonZeroIsize, AtomicUsize, AtomicIsize, f32, f64 => "number"
+}
+
+#[cfg(not(feature = "branded-char"))]
+impl_primitives!(char => "string");
+
 impl_primitives! {
-    u8, i8, NonZeroU8, NonZeroI8,
-    u16, i16, NonZeroU16, NonZeroI16,
-    u32, i32, NonZeroU32, NonZeroI32,
-    usize, isize, NonZeroUsize, NonZeroIsize, f32, f64 => "number",
     u64, i64, NonZeroU64, NonZeroI64,
     u128, i128, NonZeroU128, NonZeroI128 => "bigint",
-    bool => "boolean",
-    char, Path, PathBuf, String, str,
+    bool, AtomicBool => "boolean",
+    Path, PathBuf, String, str, OsString, OsStr, CString, CStr,
     Ipv4Addr, Ipv6Addr, IpAddr, SocketAddrV4, SocketAddrV6, SocketAddr => "string",
-    () => "null"
+    (), RangeFull => "null",
+    Infallible => "never"
 }
 
+// not every target supports 64 bit atomics
+#[cfg(target_has_atomic = "64")]
+impl_primitives! { std::sync::atomic::AtomicU64, std::sync::atomic::AtomicI64 => "bigint" }
+
 #[cfg(feature = "bigdecimal-impl")]
 impl_primitives! { bigdecimal::BigDecimal => "string" }
 
@@ -870,21 +1524,43 @@ impl_primitives! { ordered_float::OrderedFloat<f32> => "number" }
 #[cfg(feature = "ordered-float-impl")]
 impl_primitives! { ordered_float::OrderedFloat<f64> => "number" }
 
+#[cfg(feature = "bson-impl")]
+impl_primitives! { bson::oid::ObjectId, bson::DateTime, bson::Decimal128 => "string" }
+
 #[cfg(feature = "bson-uuid-impl")]
 impl_primitives! { bson::Uuid => "string" }
 
 #[cfg(feature = "indexmap-impl")]
-impl_shadow!(as Vec<T>: impl<T: TS> TS for indexmap::IndexSet<T>);
+impl_shadow!(as SetShadow<T>: impl<T: TS> TS for indexmap::IndexSet<T>);
 
-#[cfg(feature = "indexmap-impl")]
+#[cfg(all(feature = "indexmap-impl", not(feature = "indexmap-ordered")))]
 impl_shadow!(as HashMap<K, V>: impl<K: TS, V: TS> TS for indexmap::IndexMap<K, V>);
 
+// with `indexmap-ordered`, maps are serialized as a sequence of entries to preserve their order
+#[cfg(feature = "indexmap-ordered")]
+impl_shadow!(as Vec<(K, V)>: impl<K: TS, V: TS> TS for indexmap::IndexMap<K, V>);
+
 #[cfg(feature = "heapless-impl")]
 impl_shadow!(as Vec<T>: impl<T: TS, const N: usize> TS for heapless::Vec<T, N>);
 
 #[cfg(feature = "semver-impl")]
 impl_primitives! { semver::Version => "string" }
 
+#[cfg(feature = "ipnet-impl")]
+impl_primitives! { ipnet::IpNet, ipnet::Ipv4Net, ipnet::Ipv6Net => "string" }
+
+#[cfg(feature = "enumset-impl")]
+impl_shadow!(as Vec<T>: impl<T: TS + enumset::EnumSetType> TS for enumset::EnumSet<T>);
+
+#[cfg(feature = "kstring-impl")]
+impl_primitives! { kstring::KString, kstring::KStringCow<'_> => "string" }
+
+#[cfg(feature = "bytestring-impl")]
+impl_primitives! { bytestring::ByteString => "string" }
+
+#[cfg(feature = "half-impl")]
+impl_primitives! { half::f16, half::bf16 => "number" }
+
 #[cfg(feature = "bytes-impl")]
 mod bytes {
     use super::TS;
@@ -896,4 +1572,5 @@ mod bytes {
 #[rustfmt::skip]
 pub(crate) use impl_primitives;
 #[rustfmt::skip]
+#[allow(unused_imports)]
 pub(crate) use impl_shadow;
@@ -0,0 +1,58 @@
+use time::{Duration, Month, Weekday};
+
+#[cfg(feature = "time-duration-object")]
+use super::typelist::TypeList;
+use super::{impl_primitives, TS};
+
+// with the `serde-human-readable` feature of time, these are serialized as their name, e.g. "January"
+impl_primitives!(Month, Weekday => "string");
+
+// whether `Duration` is emitted as a string or as an object of its seconds and nanoseconds is up to
+// the `time-duration-object` feature
+#[cfg(not(feature = "time-duration-object"))]
+impl_primitives!(Duration => "string");
+
+// the seconds are an `i64`, but are emitted like the `i32` nanoseconds, and not as a `bigint`,
+// since durations fit into a JS number
+#[cfg(feature = "time-duration-object")]
+impl TS for Duration {
+    fn name() -> String {
+        format!(
+            "{{ seconds: {}, nanoseconds: {}, }}",
+            i32::name(),
+            i32::name()
+        )
+    }
+
+    fn decl() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn decl_concrete() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn inline() -> String {
+        Self::name()
+    }
+
+    fn inline_flattened() -> String {
+        panic!("{} cannot be flattened", Self::name())
+    }
+
+    fn dependency_types() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        // with `branded-numbers`, the brand types are imported from the prelude
+        ().push::<i32>()
+    }
+
+    fn generics() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        // like for `Range<i32>`, types referencing `Self` by name depend on the fields as well
+        ().push::<i32>()
+    }
+}
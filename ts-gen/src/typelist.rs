@@ -65,12 +65,12 @@ pub trait TypeList: Copy + Clone {
         (self, l)
     }
 
-    fn contains<C: Sized + 'static>(self) -> bool;
+    fn contains<C: ?Sized + 'static>(self) -> bool;
     fn for_each(self, v: &mut impl TypeVisitor);
 }
 
 impl TypeList for () {
-    fn contains<C: Sized + 'static>(self) -> bool {
+    fn contains<C: ?Sized + 'static>(self) -> bool {
         false
     }
 
@@ -81,7 +81,7 @@ impl<T> TypeList for (PhantomData<T>,)
 where
     T: TS + 'static + ?Sized,
 {
-    fn contains<C: Sized + 'static>(self) -> bool {
+    fn contains<C: ?Sized + 'static>(self) -> bool {
         TypeId::of::<C>() == TypeId::of::<T>()
     }
 
@@ -95,7 +95,7 @@ where
     A: TypeList,
     B: TypeList,
 {
-    fn contains<C: Sized + 'static>(self) -> bool {
+    fn contains<C: ?Sized + 'static>(self) -> bool {
         self.0.contains::<C>() || self.1.contains::<C>()
     }
 
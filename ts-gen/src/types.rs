@@ -0,0 +1,68 @@
+//! Types which only exist to be used with `#[ts(as = "..")]`, for fields whose serialized
+//! representation differs from their Rust type, e.g. because of `#[serde(with = "..")]`.
+//!
+//! ```
+//! # use std::time::Duration;
+//! # use ts_gen::TS;
+//! #[derive(TS)]
+//! struct Timeout {
+//!     #[ts(as = "ts_gen::types::DurationMillis")]
+//!     duration: Duration,
+//! }
+//!
+//! assert_eq!(Timeout::inline(), "{ duration: number, }");
+//! ```
+
+use std::marker::PhantomData;
+
+use super::{impl_primitives, typelist::TypeList, TS};
+
+/// A duration serialized as a number of milliseconds, like `serde_with::DurationMilliSeconds`
+pub struct DurationMillis;
+
+/// A duration serialized as a floating-point number of seconds, like
+/// `serde_with::DurationSecondsWithFrac<f64>`
+pub struct DurationSecondsFloat;
+
+impl_primitives! { DurationMillis, DurationSecondsFloat => "number" }
+
+/// A list which is serialized as either a single element or an array, like
+/// `serde_with::OneOrMany`. Just like in serde_with, it is used on the list itself, so
+/// `#[ts(as = "OneOrMany<_>")]` on a `Vec<T>` generates `T | Array<T>`.
+pub struct OneOrMany<T>(PhantomData<T>);
+
+impl<T: TS> TS for OneOrMany<Vec<T>> {
+    fn name() -> String {
+        format!("{} | Array<{}>", T::name(), T::name())
+    }
+
+    fn decl() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn decl_concrete() -> String {
+        panic!("{} cannot be declared", Self::name())
+    }
+
+    fn inline() -> String {
+        format!("{} | Array<{}>", T::inline(), T::inline())
+    }
+
+    fn inline_flattened() -> String {
+        panic!("{} cannot be flattened", Self::name())
+    }
+
+    fn dependency_types() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        T::dependency_types()
+    }
+
+    fn generics() -> impl TypeList
+    where
+        Self: 'static,
+    {
+        T::generics().push::<T>()
+    }
+}